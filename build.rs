@@ -0,0 +1,26 @@
+use std::process::Command;
+
+/// Exposes the commit the binary was built from as `PETCLINIC_GIT_HASH`.
+/// Builds without a git checkout (e.g. nix) can set the variable themselves.
+fn main() {
+    println!("cargo:rerun-if-env-changed=PETCLINIC_GIT_HASH");
+    println!("cargo:rerun-if-changed=.git/HEAD");
+    println!("cargo:rerun-if-changed=.git/refs");
+    // The migrations are embedded with `embed_migrations!`
+    println!("cargo:rerun-if-changed=migrations");
+
+    let hash = std::env::var("PETCLINIC_GIT_HASH").ok().or_else(|| {
+        Command::new("git")
+            .args(["rev-parse", "--short", "HEAD"])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .and_then(|output| String::from_utf8(output.stdout).ok())
+            .map(|hash| hash.trim().to_string())
+    });
+
+    println!(
+        "cargo:rustc-env=PETCLINIC_GIT_HASH={}",
+        hash.unwrap_or_else(|| "unknown".to_string())
+    );
+}
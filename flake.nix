@@ -28,7 +28,7 @@
                 name = "petclinic";
                 overrideAttrs = drv: {
                   propagatedNativeBuildInputs = drv.propagatedNativeBuildInputs or [ ] ++
-                  [ pkgs.pkg-config pkgs.sqlite pkgs.diesel-cli pkgs.redis ];
+                  [ pkgs.pkg-config pkgs.sqlite pkgs.redis ];
                 };
               })
             ];
@@ -62,9 +62,6 @@
 
           templates = ./templates;
           static = ./static;
-          migrations = ./migrations;
-
-          diesel_config = readFile ./diesel.toml;
         in
         {
           options.services.petclinic = {
@@ -113,6 +110,7 @@
               [database]
               path = "${cfg.petclinic-db-path}"
               connections = 8
+              run_migrations = true
 
               [redis]
               server = "${cfg.redis-server-name}"
@@ -122,11 +120,8 @@
             '';
 
             environment.etc."petclinic/templates".source = "${templates}";
-            environment.etc."petclinic/migrations".source = "${migrations}";
             environment.etc."petclinic/static".source = "${static}";
 
-            environment.etc."petclinic/diesel.toml".text = "${diesel_config}";
-
             users.users."petclinic" = {
               name = "petclinic";
               description = "System user for the redis-server instance petclincic ";
@@ -147,14 +142,15 @@
                   ''
                     cd /etc/petclinic
                     mkdir -p /var/lib/petclinic
-                    ${pkgs.diesel-cli}/bin/diesel migration run --migration-dir /etc/petclinic/migrations --database-url /var/lib/petclinic/db.sqlite
                     chown -R petclinic  /var/lib/petclinic
                     chgrp -R petclinic  /var/lib/petclinic
-                    chmod 0600 -R /var/lib/petclinic/*
+                    chmod -R u=rwX,go= /var/lib/petclinic
                   ''
                 );
                 User = "petclinic";
                 Group = "petclinic";
+                # templates and static are looked up relative to it
+                WorkingDirectory = "/etc/petclinic";
                 ExecStart = "${cfg.package}/bin/petclinic --config-file /etc/petclinic/release.toml";
                 Restart = "on-failure";
                 RestartSec = "5s";
@@ -43,13 +43,13 @@ INSERT INTO vet (id, name) VALUES(3, 'Linda Douglas');
 INSERT INTO vet (id, name) VALUES(4, 'Rafael Ortega');
 
 INSERT INTO pet (id, name, owner_name, owner_phone, age, pet_type, vet_id, created_at, created_by)
-VALUES(1, 'Felix', 'John Doe', '333', 3, 1, 1, '2022-01-01 9:00:00', 1);
+VALUES(1, 'Felix', 'John Doe', '555-0101', 3, 1, 1, '2022-01-01 9:00:00', 1);
 
 INSERT INTO pet (id, name, owner_name, owner_phone, age, pet_type, vet_id, created_at, created_by)
-VALUES(2, 'Chloe', 'Peter Falk', '333', 5, 2, 1, '2022-01-01 9:00:00', 1);
+VALUES(2, 'Chloe', 'Peter Falk', '555-0102', 5, 2, 1, '2022-01-01 9:00:00', 1);
 
 INSERT INTO pet (id, name, owner_name, owner_phone, age, pet_type, vet_id, created_at, created_by)
-VALUES(3, 'Iru', 'Dr.Falken', '333', 8, 2, 3, '2022-01-01 9:00:00', 1);
+VALUES(3, 'Iru', 'Dr.Falken', '555-0103', 8, 2, 3, '2022-01-01 9:00:00', 1);
 
 INSERT INTO pet (id, name, owner_name, owner_phone, age, pet_type, vet_id, created_at, created_by)
-VALUES(4, 'Willy', 'Harold Davis', '333', 10, 2, 1, '2022-01-01 9:00:00', 1);
+VALUES(4, 'Willy', 'Harold Davis', '555-0104', 10, 2, 1, '2022-01-01 9:00:00', 1);
@@ -0,0 +1,3 @@
+ALTER TABLE user ADD COLUMN role VARCHAR(20) NOT NULL DEFAULT 'read_only';
+
+UPDATE user SET role = 'admin' WHERE username = 'admin';
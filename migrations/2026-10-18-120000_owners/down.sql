@@ -0,0 +1,25 @@
+CREATE TABLE pet_old (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    owner_name VARCHAR(100) NOT NULL,
+    owner_phone VARCHAR(20) NOT NULL,
+    age INT NOT NULL,
+    pet_type INT NOT NULL,
+    vet_id INT,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    created_by INT NOT NULL,
+    FOREIGN KEY (vet_id) REFERENCES vet(id) ON DELETE CASCADE,
+    FOREIGN KEY (created_by) REFERENCES user(id)
+);
+
+INSERT INTO pet_old (id, name, owner_name, owner_phone, age, pet_type, vet_id, created_at, created_by)
+SELECT pet.id, pet.name, owner.name, owner.phone, pet.age, pet.pet_type, pet.vet_id, pet.created_at, pet.created_by
+FROM pet JOIN owner ON owner.id = pet.owner_id;
+
+-- Keep AUTOINCREMENT from reusing ids of deleted pets
+UPDATE sqlite_sequence SET seq = (SELECT seq FROM sqlite_sequence WHERE name = 'pet')
+WHERE name = 'pet_old';
+
+DROP TABLE pet;
+ALTER TABLE pet_old RENAME TO pet;
+DROP TABLE owner;
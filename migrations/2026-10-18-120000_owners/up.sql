@@ -0,0 +1,36 @@
+CREATE TABLE owner (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    phone VARCHAR(20) NOT NULL,
+    email VARCHAR(100),
+    address VARCHAR(255)
+);
+
+-- One owner per distinct name/phone pair found on the existing pets
+INSERT INTO owner (name, phone)
+SELECT owner_name, owner_phone FROM pet GROUP BY owner_name, owner_phone ORDER BY MIN(id);
+
+CREATE TABLE pet_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    owner_id INT NOT NULL,
+    age INT NOT NULL,
+    pet_type INT NOT NULL,
+    vet_id INT,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    created_by INT NOT NULL,
+    FOREIGN KEY (owner_id) REFERENCES owner(id),
+    FOREIGN KEY (vet_id) REFERENCES vet(id) ON DELETE CASCADE,
+    FOREIGN KEY (created_by) REFERENCES user(id)
+);
+
+INSERT INTO pet_new (id, name, owner_id, age, pet_type, vet_id, created_at, created_by)
+SELECT pet.id, pet.name, owner.id, pet.age, pet.pet_type, pet.vet_id, pet.created_at, pet.created_by
+FROM pet JOIN owner ON owner.name = pet.owner_name AND owner.phone = pet.owner_phone;
+
+-- Keep AUTOINCREMENT from reusing ids of deleted pets
+UPDATE sqlite_sequence SET seq = (SELECT seq FROM sqlite_sequence WHERE name = 'pet')
+WHERE name = 'pet_new';
+
+DROP TABLE pet;
+ALTER TABLE pet_new RENAME TO pet;
@@ -0,0 +1,16 @@
+CREATE TABLE appointment (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    pet_id INT NOT NULL,
+    vet_id INT NOT NULL,
+    starts_at TIMESTAMP NOT NULL,
+    ends_at TIMESTAMP NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'booked',
+    reason TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    created_by INT NOT NULL,
+    FOREIGN KEY (pet_id) REFERENCES pet(id) ON DELETE CASCADE,
+    FOREIGN KEY (vet_id) REFERENCES vet(id) ON DELETE CASCADE,
+    FOREIGN KEY (created_by) REFERENCES user(id)
+);
+
+CREATE INDEX appointment_vet_starts_at ON appointment (vet_id, starts_at);
@@ -0,0 +1,2 @@
+ALTER TABLE appointment DROP COLUMN sequence;
+DROP TABLE calendar_feed;
@@ -0,0 +1,10 @@
+CREATE TABLE calendar_feed (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    vet_id INT UNIQUE NOT NULL,
+    token_hash VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (vet_id) REFERENCES vet(id) ON DELETE CASCADE
+);
+
+-- Bumped on every change so calendar clients pick up rescheduled appointments
+ALTER TABLE appointment ADD COLUMN sequence INT NOT NULL DEFAULT 0;
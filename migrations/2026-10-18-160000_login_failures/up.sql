@@ -0,0 +1,7 @@
+-- Used when session.backend = "sqlite"
+CREATE TABLE login_failure (
+    key VARCHAR(255) PRIMARY KEY NOT NULL,
+    count INT NOT NULL,
+    last_failed_at TIMESTAMP NOT NULL,
+    expires_at TIMESTAMP NOT NULL
+);
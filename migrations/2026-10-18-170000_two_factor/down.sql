@@ -0,0 +1,3 @@
+DROP TABLE pending_login;
+DROP TABLE recovery_code;
+DROP TABLE user_totp;
@@ -0,0 +1,27 @@
+-- RFC 6238 secret of a user, `enabled` once the first code was confirmed
+CREATE TABLE user_totp (
+    user_id INTEGER PRIMARY KEY NOT NULL,
+    secret VARCHAR(64) NOT NULL,
+    enabled BOOLEAN NOT NULL DEFAULT 0,
+    last_used_step BIGINT,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (user_id) REFERENCES user(id) ON DELETE CASCADE
+);
+
+CREATE TABLE recovery_code (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    user_id INT NOT NULL,
+    code_hash VARCHAR(64) NOT NULL,
+    used_at TIMESTAMP,
+    FOREIGN KEY (user_id) REFERENCES user(id) ON DELETE CASCADE
+);
+
+CREATE INDEX recovery_code_user_id ON recovery_code (user_id);
+
+-- Used when session.backend = "sqlite"
+CREATE TABLE pending_login (
+    key VARCHAR(64) PRIMARY KEY NOT NULL,
+    user_id INT NOT NULL,
+    expires_at TIMESTAMP NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES user(id) ON DELETE CASCADE
+);
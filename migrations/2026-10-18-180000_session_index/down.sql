@@ -0,0 +1,12 @@
+DROP TABLE session;
+
+CREATE TABLE session (
+    key VARCHAR(64) PRIMARY KEY NOT NULL,
+    user_id INT NOT NULL,
+    data TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    expires_at TIMESTAMP NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES user(id) ON DELETE CASCADE
+);
+
+CREATE INDEX session_expires_at ON session (expires_at);
@@ -0,0 +1,18 @@
+-- Sessions are recreated with the details shown on the sessions page, which
+-- logs everyone out once
+DROP TABLE session;
+
+CREATE TABLE session (
+    key VARCHAR(64) PRIMARY KEY NOT NULL,
+    user_id INT NOT NULL,
+    data TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    expires_at TIMESTAMP NOT NULL,
+    last_seen_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    ip VARCHAR(45),
+    user_agent VARCHAR(255),
+    FOREIGN KEY (user_id) REFERENCES user(id) ON DELETE CASCADE
+);
+
+CREATE INDEX session_expires_at ON session (expires_at);
+CREATE INDEX session_user_id ON session (user_id);
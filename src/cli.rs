@@ -0,0 +1,301 @@
+use crate::{
+    db::{
+        migrations,
+        models::{
+            api_token::ApiToken,
+            user::{Role, User},
+        },
+    },
+    logic::{api_tokens, audit::Actor, users},
+    settings::Settings,
+};
+use anyhow::{bail, Result};
+use argh::FromArgs;
+use diesel::{Connection, SqliteConnection};
+use std::io::{self, IsTerminal};
+
+/// Administrative subcommands, run instead of the web server
+#[derive(Debug, FromArgs)]
+#[argh(subcommand)]
+pub enum Command {
+    User(UserCommand),
+    Token(TokenCommand),
+    Migrate(MigrateCommand),
+}
+
+/// manage user accounts
+#[derive(Debug, FromArgs)]
+#[argh(subcommand, name = "user")]
+pub struct UserCommand {
+    #[argh(subcommand)]
+    action: UserAction,
+}
+
+#[derive(Debug, FromArgs)]
+#[argh(subcommand)]
+enum UserAction {
+    Add(UserAdd),
+    List(UserList),
+    Passwd(UserPasswd),
+    Role(UserRole),
+    Disable(UserDisable),
+    Enable(UserEnable),
+}
+
+/// create a new user, prompting for the password
+#[derive(Debug, FromArgs)]
+#[argh(subcommand, name = "add")]
+struct UserAdd {
+    /// login name of the new user
+    #[argh(positional)]
+    username: String,
+
+    /// role of the new user: admin, vet, receptionist or read_only (default)
+    #[argh(option, default = "Role::default()")]
+    role: Role,
+}
+
+/// list all users
+#[derive(Debug, FromArgs)]
+#[argh(subcommand, name = "list")]
+struct UserList {}
+
+/// reset the password of a user, prompting for the new one
+#[derive(Debug, FromArgs)]
+#[argh(subcommand, name = "passwd")]
+struct UserPasswd {
+    /// login name of the user
+    #[argh(positional)]
+    username: String,
+}
+
+/// change the role of a user
+#[derive(Debug, FromArgs)]
+#[argh(subcommand, name = "role")]
+struct UserRole {
+    /// login name of the user
+    #[argh(positional)]
+    username: String,
+
+    /// new role: admin, vet, receptionist or read_only
+    #[argh(positional)]
+    role: Role,
+}
+
+/// prevent a user from logging in
+#[derive(Debug, FromArgs)]
+#[argh(subcommand, name = "disable")]
+struct UserDisable {
+    /// login name of the user
+    #[argh(positional)]
+    username: String,
+}
+
+/// allow a disabled user to log in again
+#[derive(Debug, FromArgs)]
+#[argh(subcommand, name = "enable")]
+struct UserEnable {
+    /// login name of the user
+    #[argh(positional)]
+    username: String,
+}
+
+/// manage bearer tokens for the JSON API
+#[derive(Debug, FromArgs)]
+#[argh(subcommand, name = "token")]
+pub struct TokenCommand {
+    #[argh(subcommand)]
+    action: TokenAction,
+}
+
+#[derive(Debug, FromArgs)]
+#[argh(subcommand)]
+enum TokenAction {
+    Create(TokenCreate),
+    List(TokenList),
+    Revoke(TokenRevoke),
+}
+
+/// issue a new token acting as the given user
+#[derive(Debug, FromArgs)]
+#[argh(subcommand, name = "create")]
+struct TokenCreate {
+    /// login name of the user the token acts as
+    #[argh(positional)]
+    username: String,
+
+    /// label describing what the token is used for
+    #[argh(positional)]
+    name: String,
+}
+
+/// list all issued tokens
+#[derive(Debug, FromArgs)]
+#[argh(subcommand, name = "list")]
+struct TokenList {}
+
+/// revoke a token by id
+#[derive(Debug, FromArgs)]
+#[argh(subcommand, name = "revoke")]
+struct TokenRevoke {
+    /// id of the token, as shown by `token list`
+    #[argh(positional)]
+    id: i32,
+}
+
+/// apply or revert the database migrations built into the binary
+#[derive(Debug, FromArgs)]
+#[argh(subcommand, name = "migrate")]
+pub struct MigrateCommand {
+    #[argh(subcommand)]
+    action: MigrateAction,
+}
+
+#[derive(Debug, FromArgs)]
+#[argh(subcommand)]
+enum MigrateAction {
+    Up(MigrateUp),
+    Down(MigrateDown),
+    Status(MigrateStatus),
+}
+
+/// run every pending migration
+#[derive(Debug, FromArgs)]
+#[argh(subcommand, name = "up")]
+struct MigrateUp {}
+
+/// revert the most recently applied migrations
+#[derive(Debug, FromArgs)]
+#[argh(subcommand, name = "down")]
+struct MigrateDown {
+    /// number of migrations to revert (default 1)
+    #[argh(option, default = "1")]
+    steps: usize,
+}
+
+/// list the migrations and whether each one is applied
+#[derive(Debug, FromArgs)]
+#[argh(subcommand, name = "status")]
+struct MigrateStatus {}
+
+/// Prompts on the terminal, or reads a single line when stdin is piped (e.g. from scripts).
+fn prompt_new_password() -> Result<String> {
+    if !io::stdin().is_terminal() {
+        let mut password = String::new();
+        io::stdin().read_line(&mut password)?;
+        return Ok(password.trim_end_matches(['\r', '\n']).to_string());
+    }
+
+    let password = rpassword::prompt_password("Password: ")?;
+    let confirmation = rpassword::prompt_password("Repeat password: ")?;
+    if password != confirmation {
+        bail!("Passwords do not match");
+    }
+    Ok(password)
+}
+
+pub fn run(command: Command, settings: &Settings) -> Result<()> {
+    let conn = &mut SqliteConnection::establish(&settings.database.path)?;
+    let actor = Actor::cli();
+
+    match command {
+        Command::User(UserCommand { action }) => match action {
+            UserAction::Add(UserAdd { username, role }) => {
+                let password = prompt_new_password()?;
+                users::create_user(conn, &actor, &username, &password, role)?;
+                println!("User {username} created with role {role}");
+            }
+            UserAction::List(UserList {}) => {
+                for user in User::users(conn)? {
+                    let status = if user.disabled { "disabled" } else { "enabled" };
+                    println!(
+                        "{:>5}  {:<30}  {:<12}  {status}",
+                        user.id, user.username, user.role
+                    );
+                }
+            }
+            UserAction::Role(UserRole { username, role }) => {
+                let user = User::select_by_name(conn, &username)?;
+                users::set_role(conn, &actor, user.id, role)?;
+                println!("User {username} now has role {role}");
+            }
+            UserAction::Passwd(UserPasswd { username }) => {
+                let user = User::select_by_name(conn, &username)?;
+                let password = prompt_new_password()?;
+                users::reset_password(conn, &actor, user.id, &password)?;
+                println!("Password of {username} updated");
+            }
+            UserAction::Disable(UserDisable { username }) => {
+                let user = User::select_by_name(conn, &username)?;
+                users::set_disabled(conn, &actor, user.id, true)?;
+                println!("User {username} disabled");
+            }
+            UserAction::Enable(UserEnable { username }) => {
+                let user = User::select_by_name(conn, &username)?;
+                users::set_disabled(conn, &actor, user.id, false)?;
+                println!("User {username} enabled");
+            }
+        },
+        Command::Token(TokenCommand { action }) => match action {
+            TokenAction::Create(TokenCreate { username, name }) => {
+                let user = User::select_by_name(conn, &username)?;
+                let (api_token, token) = api_tokens::create_token(conn, user.id, &name)?;
+                println!(
+                    "Token {} created for {username}, store it now:",
+                    api_token.id
+                );
+                println!("{token}");
+            }
+            TokenAction::List(TokenList {}) => {
+                let users = User::users(conn)?;
+                for api_token in ApiToken::tokens(conn)? {
+                    let username = users
+                        .iter()
+                        .find(|user| user.id == api_token.user_id)
+                        .map_or("?", |user| user.username.as_str());
+                    let last_used = api_token
+                        .last_used_at
+                        .map_or("never".to_string(), |t| t.to_string());
+                    println!(
+                        "{:>5}  {:<20}  {:<30}  created {}  last used {last_used}",
+                        api_token.id, username, api_token.name, api_token.created_at
+                    );
+                }
+            }
+            TokenAction::Revoke(TokenRevoke { id }) => {
+                if ApiToken::delete_by_id(conn, id)? == 0 {
+                    bail!("Token {id} not found");
+                }
+                println!("Token {id} revoked");
+            }
+        },
+        Command::Migrate(MigrateCommand { action }) => match action {
+            MigrateAction::Up(MigrateUp {}) => {
+                let applied = migrations::run_pending(conn)?;
+                if applied.is_empty() {
+                    println!("Database is up to date");
+                }
+                for name in applied {
+                    println!("Applied {name}");
+                }
+            }
+            MigrateAction::Down(MigrateDown { steps }) => {
+                for _ in 0..steps {
+                    println!("Reverted {}", migrations::revert_last(conn)?);
+                }
+            }
+            MigrateAction::Status(MigrateStatus {}) => {
+                for migration in migrations::status(conn)? {
+                    let status = if migration.applied {
+                        "applied"
+                    } else {
+                        "pending"
+                    };
+                    println!("{status:<8}  {}", migration.name);
+                }
+            }
+        },
+    }
+
+    Ok(())
+}
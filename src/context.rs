@@ -1,35 +1,61 @@
 use anyhow::Result;
-use deadpool_diesel::{sqlite::Pool, Manager, Runtime};
-use redis::Connection;
-use std::sync::Arc;
-use tokio::sync::Mutex;
+use deadpool_diesel::{sqlite::Pool, Manager, PoolError, Runtime};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+use tracing::Instrument;
 
+use crate::db::PooledConnection;
+use crate::metrics::metrics;
+use crate::session::{self, SessionStore};
 use crate::settings::Settings;
 
 pub struct Context {
     pub db_connection_pool: Pool,
-    pub redis_connection: Mutex<Connection>,
+    pub sessions: Arc<dyn SessionStore>,
     pub settings: Arc<Settings>,
+    /// Set once shutdown starts, failing the readiness probe.
+    draining: AtomicBool,
 }
 
 impl Context {
     pub fn new(settings: Arc<Settings>) -> Result<Self> {
-        let redis_url = match &settings.redis.password {
-            Some(password) => format!("redis://:{}@{}", password, settings.redis.server),
-            None => format!("redis://{}", settings.redis.server),
-        };
-
-        let client = redis::Client::open(redis_url)?;
-        let redis_connection = client.get_connection()?;
-
         let manager = Manager::new(&settings.database.path, Runtime::Tokio1);
+        let db_connection_pool = Pool::builder(manager)
+            .max_size(settings.database.connections)
+            .build()?;
 
         Ok(Self {
-            db_connection_pool: Pool::builder(manager)
-                .max_size(settings.database.connections)
-                .build()?,
-            redis_connection: Mutex::new(redis_connection),
+            sessions: session::from_settings(&settings, &db_connection_pool)?,
+            db_connection_pool,
             settings,
+            draining: AtomicBool::new(false),
         })
     }
+
+    /// A pooled SQLite connection, recording how long the pool made us wait.
+    pub async fn db_connection(&self) -> Result<PooledConnection, PoolError> {
+        let _timer = metrics().db_pool_wait.start_timer();
+        self.db_connection_pool
+            .get()
+            .instrument(tracing::info_span!("db.acquire"))
+            .await
+            .map(PooledConnection::from)
+    }
+
+    pub fn start_draining(&self) {
+        self.draining.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_draining(&self) -> bool {
+        self.draining.load(Ordering::SeqCst)
+    }
+
+    /// Closes the database and session store connections once every request
+    /// finished.
+    pub fn close(&self) {
+        self.sessions.close();
+        self.db_connection_pool.close();
+    }
 }
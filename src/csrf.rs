@@ -0,0 +1,248 @@
+//! CSRF protection for the cookie-authenticated HTML routes.
+//!
+//! Every browser gets a random token in the `csrf_token` cookie, replaced on
+//! login and logout. Pages embed it through the `csrf_token()` Tera function
+//! (see `partials/csrf.html`) and [`protect`] rejects any state-changing
+//! request whose `csrf_token` form field or `X-CSRF-Token` header doesn't
+//! match the cookie. A cross-site page can neither read the cookie nor make
+//! the browser send a matching field.
+
+use crate::{settings::Settings, AppError};
+use axum::{
+    body::{Body, Bytes},
+    extract::{Extension, FromRequest, Request},
+    http::{header, HeaderMap, Method},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use axum_extra::extract::cookie::{Cookie, CookieJar, SameSite};
+use rand::{distributions::Alphanumeric, Rng};
+use serde_json::Value;
+use std::{collections::HashMap, sync::Arc};
+use subtle::ConstantTimeEq;
+use tera::Tera;
+
+pub const COOKIE_NAME: &str = "csrf_token";
+const FIELD_NAME: &str = "csrf_token";
+const HEADER_NAME: &str = "x-csrf-token";
+
+pub fn new_token() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(32)
+        .map(char::from)
+        .collect()
+}
+
+pub fn cookie(settings: &Settings, token: String) -> Cookie<'static> {
+    Cookie::build((COOKIE_NAME, token))
+        .path("/")
+        .http_only(true)
+        .same_site(SameSite::Lax)
+        .secure(settings.session.secure_cookie)
+        .build()
+}
+
+struct CsrfToken {
+    token: String,
+}
+
+impl tera::Function for CsrfToken {
+    fn call(&self, _args: &HashMap<String, Value>) -> tera::Result<Value> {
+        Ok(Value::String(self.token.clone()))
+    }
+}
+
+fn is_state_changing(method: &Method) -> bool {
+    !matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS)
+}
+
+fn is_form(headers: &HeaderMap) -> bool {
+    headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/x-www-form-urlencoded"))
+}
+
+/// Compares in constant time, so the cookie can't be guessed from response times.
+fn tokens_match(submitted: Option<&str>, expected: &str) -> bool {
+    submitted.is_some_and(|submitted| bool::from(submitted.as_bytes().ct_eq(expected.as_bytes())))
+}
+
+/// Middleware issuing the token cookie and verifying unsafe requests.
+pub async fn protect(
+    Extension(settings): Extension<Arc<Settings>>,
+    jar: CookieJar,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let existing = jar.get(COOKIE_NAME).map(|c| c.value().to_string());
+
+    if is_state_changing(request.method()) {
+        let Some(expected) = &existing else {
+            return forbidden();
+        };
+
+        let submitted = match request
+            .headers()
+            .get(HEADER_NAME)
+            .and_then(|value| value.to_str().ok())
+        {
+            Some(token) => Some(token.to_string()),
+            None if is_form(request.headers()) => {
+                let (parts, body) = request.into_parts();
+                // Buffered with axum's default body limit, then handed on to the handler
+                let bytes = match Bytes::from_request(Request::new(body), &()).await {
+                    Ok(bytes) => bytes,
+                    Err(rejection) => return rejection.into_response(),
+                };
+                let token = serde_urlencoded::from_bytes::<Vec<(String, String)>>(&bytes)
+                    .ok()
+                    .and_then(|fields| {
+                        fields
+                            .into_iter()
+                            .find(|(name, _)| name == FIELD_NAME)
+                            .map(|(_, value)| value)
+                    });
+                request = Request::from_parts(parts, Body::from(bytes));
+                token
+            }
+            None => None,
+        };
+
+        if !tokens_match(submitted.as_deref(), expected) {
+            tracing::warn!(
+                "Rejected {} {} with a missing or wrong CSRF token",
+                request.method(),
+                request.uri().path()
+            );
+            return forbidden();
+        }
+    }
+
+    let token = existing.clone().unwrap_or_else(new_token);
+    if let Some(tera) = request.extensions_mut().get_mut::<Tera>() {
+        tera.register_function(
+            "csrf_token",
+            CsrfToken {
+                token: token.clone(),
+            },
+        );
+    }
+
+    let response = next.run(request).await;
+
+    if existing.is_none() {
+        (CookieJar::new().add(cookie(&settings, token)), response).into_response()
+    } else {
+        response
+    }
+}
+
+/// Goes through [`crate::errors::pages`] like any other refused request.
+fn forbidden() -> Response {
+    AppError::Forbidden.into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::{self, ErrorPages};
+    use axum::{http::StatusCode, middleware, routing::post, Router};
+    use tower::ServiceExt;
+
+    const TOKEN: &str = "0123456789abcdefghijklmnopqrstuv";
+
+    fn app() -> Router {
+        Router::new()
+            .route(
+                "/form",
+                post(|body: String| async move { body }).get(|| async {}),
+            )
+            .layer(middleware::from_fn(protect))
+            .layer(middleware::from_fn(errors::pages))
+            .layer(Extension(ErrorPages(Arc::new(Tera::default()))))
+            .layer(Extension(Arc::new(Settings::default())))
+    }
+
+    fn post_form(cookie: Option<&str>, body: &str) -> Request {
+        let mut request = Request::post("/form")
+            .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        if let Some(cookie) = cookie {
+            let value = format!("{COOKIE_NAME}={cookie}").parse().unwrap();
+            request.headers_mut().insert(header::COOKIE, value);
+        }
+        request
+    }
+
+    #[test]
+    fn tokens_must_match_exactly() {
+        assert!(tokens_match(Some(TOKEN), TOKEN));
+        assert!(!tokens_match(None, TOKEN));
+        assert!(!tokens_match(Some(""), TOKEN));
+        assert!(!tokens_match(Some(&TOKEN[1..]), TOKEN));
+        assert!(!tokens_match(Some(&TOKEN.to_uppercase()), TOKEN));
+        assert!(!tokens_match(Some(&format!("{TOKEN}x")), TOKEN));
+    }
+
+    #[tokio::test]
+    async fn safe_requests_get_a_token() {
+        let response = app()
+            .oneshot(Request::get("/form").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let cookie = response.headers()[header::SET_COOKIE].to_str().unwrap();
+        assert!(cookie.starts_with(COOKIE_NAME));
+        assert!(cookie.contains("HttpOnly"));
+    }
+
+    #[tokio::test]
+    async fn forms_need_the_cookie_token() {
+        let body = format!("name=Rex&{FIELD_NAME}={TOKEN}");
+        for request in [
+            post_form(None, &body),
+            post_form(Some("other"), &body),
+            post_form(Some(TOKEN), "name=Rex"),
+        ] {
+            let response = app().oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        }
+
+        let response = app().oneshot(post_form(Some(TOKEN), &body)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        // The handler still gets the whole form
+        let handled = Bytes::from_request(Request::new(response.into_body()), &())
+            .await
+            .unwrap();
+        assert_eq!(handled, body.as_bytes());
+    }
+
+    #[tokio::test]
+    async fn header_token_is_accepted() {
+        let mut request = post_form(Some(TOKEN), "name=Rex");
+        request
+            .headers_mut()
+            .insert(HEADER_NAME, TOKEN.parse().unwrap());
+        let response = app().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn rejections_are_error_pages() {
+        let mut request = post_form(None, "name=Rex");
+        request
+            .headers_mut()
+            .insert(header::ACCEPT, "application/json".parse().unwrap());
+        let response = app().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let body = Bytes::from_request(Request::new(response.into_body()), &())
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert!(body["error"].is_string());
+        assert!(body.get("request_id").is_some());
+    }
+}
@@ -0,0 +1,56 @@
+//! The SQL migrations of `migrations/`, compiled into the binary so a
+//! deployment doesn't need the diesel CLI to set up or upgrade its database.
+
+use anyhow::{anyhow, Result};
+use diesel::{migration::MigrationSource, sqlite::Sqlite, SqliteConnection};
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+
+const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
+
+/// Whether a migration has been run against the database.
+pub struct MigrationStatus {
+    pub name: String,
+    pub applied: bool,
+}
+
+/// Runs every migration not applied yet, returning their names.
+pub fn run_pending(conn: &mut SqliteConnection) -> Result<Vec<String>> {
+    let pending = conn
+        .pending_migrations(MIGRATIONS)
+        .map_err(|e| anyhow!(e))?;
+    conn.run_migrations(&pending).map_err(|e| anyhow!(e))?;
+
+    Ok(pending
+        .iter()
+        .map(|migration| migration.name().to_string())
+        .collect())
+}
+
+/// Reverts the most recently applied migration, returning its name.
+pub fn revert_last(conn: &mut SqliteConnection) -> Result<String> {
+    let version = conn
+        .revert_last_migration(MIGRATIONS)
+        .map_err(|e| anyhow!(e))?;
+    let name = MigrationSource::<Sqlite>::migrations(&MIGRATIONS)
+        .map_err(|e| anyhow!(e))?
+        .iter()
+        .map(|migration| migration.name())
+        .find(|name| name.version() == version)
+        .map_or_else(|| version.to_string(), ToString::to_string);
+
+    Ok(name)
+}
+
+/// Every embedded migration in order, with whether it was applied.
+pub fn status(conn: &mut SqliteConnection) -> Result<Vec<MigrationStatus>> {
+    let applied = conn.applied_migrations().map_err(|e| anyhow!(e))?;
+    let migrations = MigrationSource::<Sqlite>::migrations(&MIGRATIONS).map_err(|e| anyhow!(e))?;
+
+    Ok(migrations
+        .iter()
+        .map(|migration| MigrationStatus {
+            name: migration.name().to_string(),
+            applied: applied.contains(&migration.name().version()),
+        })
+        .collect())
+}
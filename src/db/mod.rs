@@ -1,2 +1,32 @@
+pub mod migrations;
 pub mod models;
 pub mod schema;
+
+use deadpool_diesel::{sqlite::Object, InteractError};
+use diesel::SqliteConnection;
+use tracing::Span;
+
+/// A pooled SQLite connection.
+///
+/// Closures passed to [`interact`](Self::interact) run on a blocking thread,
+/// inside a `db.interact` span under the caller's so the model method spans
+/// they open end up in the request's trace.
+pub struct PooledConnection(Object);
+
+impl From<Object> for PooledConnection {
+    fn from(object: Object) -> Self {
+        Self(object)
+    }
+}
+
+impl PooledConnection {
+    pub async fn interact<F, R>(&self, f: F) -> Result<R, InteractError>
+    where
+        F: FnOnce(&mut SqliteConnection) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let span =
+            tracing::info_span!(parent: Span::current(), "db.interact", otel.kind = "client");
+        self.0.interact(move |conn| span.in_scope(|| f(conn))).await
+    }
+}
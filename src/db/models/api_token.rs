@@ -0,0 +1,94 @@
+use crate::db::schema::api_token;
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use diesel::{
+    backend::Backend,
+    dsl::{AsSelect, Eq},
+    prelude::*,
+    Identifiable, Insertable, QueryDsl, QueryResult, Queryable, RunQueryDsl, Selectable,
+    SelectableHelper,
+};
+use serde::Serialize;
+use tracing::instrument;
+
+/// Bearer token used by the JSON API. Only the SHA-256 of the token is stored.
+#[derive(Clone, Debug, Eq, Identifiable, PartialEq, Queryable, Selectable, Serialize)]
+#[diesel(table_name = api_token)]
+pub struct ApiToken {
+    pub id: i32,
+    pub user_id: i32,
+    pub name: String,
+    #[serde(skip_serializing)]
+    pub token_hash: String,
+    pub created_at: NaiveDateTime,
+    pub last_used_at: Option<NaiveDateTime>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = api_token)]
+pub struct NewApiToken {
+    pub user_id: i32,
+    pub name: String,
+    pub token_hash: String,
+    pub created_at: NaiveDateTime,
+}
+
+type All<DB> = diesel::dsl::Select<api_token::table, AsSelect<ApiToken, DB>>;
+type WithId = Eq<api_token::id, i32>;
+type WithHash<'a> = Eq<api_token::token_hash, &'a str>;
+
+impl ApiToken {
+    pub fn all<DB>() -> All<DB>
+    where
+        DB: Backend,
+    {
+        api_token::table.select(ApiToken::as_select())
+    }
+
+    fn with_id(id: i32) -> WithId {
+        api_token::id.eq(id)
+    }
+
+    fn with_hash(token_hash: &str) -> WithHash<'_> {
+        api_token::token_hash.eq(token_hash)
+    }
+
+    #[instrument(name = "ApiToken::tokens", skip_all)]
+    pub fn tokens(conn: &mut SqliteConnection) -> QueryResult<Vec<Self>> {
+        Self::all().load(conn)
+    }
+
+    #[instrument(name = "ApiToken::select_by_hash", skip_all)]
+    pub fn select_by_hash(conn: &mut SqliteConnection, token_hash: &str) -> Result<Option<Self>> {
+        Ok(crate::db::schema::api_token::table
+            .filter(Self::with_hash(token_hash))
+            .get_result::<Self>(conn)
+            .optional()?)
+    }
+
+    #[instrument(name = "ApiToken::touch", skip_all)]
+    pub fn touch(conn: &mut SqliteConnection, id: i32, now: NaiveDateTime) -> Result<usize> {
+        Ok(diesel::update(crate::db::schema::api_token::table)
+            .filter(Self::with_id(id))
+            .set(api_token::last_used_at.eq(now))
+            .execute(conn)?)
+    }
+
+    #[instrument(name = "ApiToken::delete_by_id", skip_all)]
+    pub fn delete_by_id(conn: &mut SqliteConnection, id: i32) -> Result<usize> {
+        Ok(
+            diesel::delete(crate::db::schema::api_token::table.filter(Self::with_id(id)))
+                .execute(conn)?,
+        )
+    }
+}
+
+impl NewApiToken {
+    #[instrument(name = "NewApiToken::save", skip_all)]
+    pub fn save(self, conn: &mut SqliteConnection) -> Result<ApiToken> {
+        Ok(diesel::insert_into(crate::db::schema::api_token::table)
+            .values(&self)
+            .returning(ApiToken::as_returning())
+            .get_result(conn)?)
+    }
+}
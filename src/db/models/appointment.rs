@@ -0,0 +1,248 @@
+use crate::db::schema::appointment;
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use diesel::{
+    backend::Backend,
+    deserialize::{self, FromSql, FromSqlRow},
+    dsl::{AsSelect, Eq},
+    expression::AsExpression,
+    prelude::*,
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::Text,
+    sqlite::{Sqlite, SqliteValue},
+    AsChangeset, Identifiable, Insertable, QueryDsl, Queryable, RunQueryDsl, Selectable,
+    SelectableHelper,
+};
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+use tracing::instrument;
+
+#[derive(
+    AsExpression, Clone, Copy, Debug, Default, Deserialize, Eq, FromSqlRow, PartialEq, Serialize,
+)]
+#[diesel(sql_type = Text)]
+#[serde(rename_all = "snake_case")]
+pub enum AppointmentStatus {
+    #[default]
+    Booked,
+    CheckedIn,
+    Completed,
+    NoShow,
+    Cancelled,
+}
+
+impl AppointmentStatus {
+    pub const ALL: [AppointmentStatus; 5] = [
+        AppointmentStatus::Booked,
+        AppointmentStatus::CheckedIn,
+        AppointmentStatus::Completed,
+        AppointmentStatus::NoShow,
+        AppointmentStatus::Cancelled,
+    ];
+
+    /// Statuses that keep the vet's time slot occupied.
+    pub const OCCUPYING: [AppointmentStatus; 3] = [
+        AppointmentStatus::Booked,
+        AppointmentStatus::CheckedIn,
+        AppointmentStatus::Completed,
+    ];
+
+    pub fn occupies_slot(&self) -> bool {
+        Self::OCCUPYING.contains(self)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AppointmentStatus::Booked => "booked",
+            AppointmentStatus::CheckedIn => "checked_in",
+            AppointmentStatus::Completed => "completed",
+            AppointmentStatus::NoShow => "no_show",
+            AppointmentStatus::Cancelled => "cancelled",
+        }
+    }
+}
+
+impl fmt::Display for AppointmentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl FromStr for AppointmentStatus {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        AppointmentStatus::ALL
+            .into_iter()
+            .find(|status| status.as_str() == s)
+            .ok_or_else(|| anyhow::anyhow!("Unknown appointment status: {s}"))
+    }
+}
+
+impl ToSql<Text, Sqlite> for AppointmentStatus {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        out.set_value(self.as_str());
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<Text, Sqlite> for AppointmentStatus {
+    fn from_sql(bytes: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+        let s = <String as FromSql<Text, Sqlite>>::from_sql(bytes)?;
+        Ok(s.parse()?)
+    }
+}
+
+#[derive(
+    AsChangeset,
+    Clone,
+    Debug,
+    Deserialize,
+    Eq,
+    Identifiable,
+    PartialEq,
+    Queryable,
+    Selectable,
+    Serialize,
+    Insertable,
+    Default,
+)]
+#[diesel(table_name = appointment, treat_none_as_null = true)]
+pub struct Appointment {
+    pub id: i32,
+    pub pet_id: i32,
+    pub vet_id: i32,
+    pub starts_at: NaiveDateTime,
+    pub ends_at: NaiveDateTime,
+    pub status: AppointmentStatus,
+    pub reason: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub created_by: i32,
+    /// Revision number exported to calendar feeds, bumped on every change.
+    pub sequence: i32,
+}
+
+#[derive(Serialize, Insertable)]
+#[diesel(table_name = appointment)]
+pub struct NewAppointment {
+    pub pet_id: i32,
+    pub vet_id: i32,
+    pub starts_at: NaiveDateTime,
+    pub ends_at: NaiveDateTime,
+    pub status: AppointmentStatus,
+    pub reason: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub created_by: i32,
+}
+
+type All<DB> = diesel::dsl::Select<appointment::table, AsSelect<Appointment, DB>>;
+type WithId = Eq<appointment::id, i32>;
+type WithPet = Eq<appointment::pet_id, i32>;
+type WithVet = Eq<appointment::vet_id, i32>;
+
+impl Appointment {
+    pub fn all<DB>() -> All<DB>
+    where
+        DB: Backend,
+    {
+        appointment::table.select(Appointment::as_select())
+    }
+
+    fn with_id(id: i32) -> WithId {
+        appointment::id.eq(id)
+    }
+
+    fn with_pet(pet_id: i32) -> WithPet {
+        appointment::pet_id.eq(pet_id)
+    }
+
+    fn with_vet(vet_id: i32) -> WithVet {
+        appointment::vet_id.eq(vet_id)
+    }
+
+    #[instrument(name = "Appointment::select_by_id", skip_all)]
+    pub fn select_by_id(conn: &mut SqliteConnection, id: i32) -> Result<Option<Self>> {
+        Ok(crate::db::schema::appointment::table
+            .filter(Self::with_id(id))
+            .get_result::<Self>(conn)
+            .optional()?)
+    }
+
+    #[instrument(name = "Appointment::select_by_pet", skip_all)]
+    pub fn select_by_pet(conn: &mut SqliteConnection, pet_id: i32) -> Result<Vec<Self>> {
+        Ok(crate::db::schema::appointment::table
+            .filter(Self::with_pet(pet_id))
+            .order(appointment::starts_at)
+            .get_results::<Self>(conn)?)
+    }
+
+    #[instrument(name = "Appointment::select_by_vet", skip_all)]
+    pub fn select_by_vet(conn: &mut SqliteConnection, vet_id: i32) -> Result<Vec<Self>> {
+        Ok(crate::db::schema::appointment::table
+            .filter(Self::with_vet(vet_id))
+            .order(appointment::starts_at)
+            .get_results::<Self>(conn)?)
+    }
+
+    /// Appointments of `vet_id` starting in `[from, to)`, ordered by start time.
+    #[instrument(name = "Appointment::select_by_vet_between", skip_all)]
+    pub fn select_by_vet_between(
+        conn: &mut SqliteConnection,
+        vet_id: i32,
+        from: NaiveDateTime,
+        to: NaiveDateTime,
+    ) -> Result<Vec<Self>> {
+        Ok(crate::db::schema::appointment::table
+            .filter(Self::with_vet(vet_id))
+            .filter(appointment::starts_at.ge(from))
+            .filter(appointment::starts_at.lt(to))
+            .order(appointment::starts_at)
+            .get_results::<Self>(conn)?)
+    }
+
+    /// Appointments of `vet_id` occupying any part of `[starts_at, ends_at)`,
+    /// ignoring `exclude_id` (the appointment being rescheduled).
+    #[instrument(name = "Appointment::select_overlapping", skip_all)]
+    pub fn select_overlapping(
+        conn: &mut SqliteConnection,
+        vet_id: i32,
+        starts_at: NaiveDateTime,
+        ends_at: NaiveDateTime,
+        exclude_id: i32,
+    ) -> Result<Vec<Self>> {
+        Ok(crate::db::schema::appointment::table
+            .filter(Self::with_vet(vet_id))
+            .filter(appointment::id.ne(exclude_id))
+            .filter(appointment::status.eq_any(AppointmentStatus::OCCUPYING))
+            .filter(appointment::starts_at.lt(ends_at))
+            .filter(appointment::ends_at.gt(starts_at))
+            .order(appointment::starts_at)
+            .get_results::<Self>(conn)?)
+    }
+
+    #[instrument(name = "Appointment::delete_by_id", skip_all)]
+    pub fn delete_by_id(conn: &mut SqliteConnection, id: i32) -> Result<usize> {
+        Ok(
+            diesel::delete(crate::db::schema::appointment::table.filter(Self::with_id(id)))
+                .execute(conn)?,
+        )
+    }
+
+    #[instrument(name = "Appointment::update", skip_all)]
+    pub fn update(self, conn: &mut SqliteConnection) -> Result<usize> {
+        Ok(diesel::update(crate::db::schema::appointment::table)
+            .filter(Self::with_id(self.id))
+            .set(self)
+            .execute(conn)?)
+    }
+}
+
+impl NewAppointment {
+    #[instrument(name = "NewAppointment::save", skip_all)]
+    pub fn save(self, conn: &mut SqliteConnection) -> Result<Appointment> {
+        Ok(diesel::insert_into(crate::db::schema::appointment::table)
+            .values(&self)
+            .returning(Appointment::as_returning())
+            .get_result(conn)?)
+    }
+}
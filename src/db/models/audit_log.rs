@@ -0,0 +1,103 @@
+use crate::db::schema::audit_log;
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use diesel::{
+    backend::Backend, dsl::AsSelect, prelude::*, sqlite::Sqlite, Identifiable, Insertable,
+    QueryDsl, Queryable, RunQueryDsl, Selectable, SelectableHelper,
+};
+use serde::Serialize;
+use tracing::instrument;
+
+/// One create, update or delete of a record. `changes` is a JSON object
+/// mapping each changed field to its `before` and `after` values.
+#[derive(Clone, Debug, Eq, Identifiable, PartialEq, Queryable, Selectable, Serialize)]
+#[diesel(table_name = audit_log)]
+pub struct AuditLog {
+    pub id: i32,
+    /// `None` for changes made from the command line
+    pub actor_id: Option<i32>,
+    pub actor_name: String,
+    pub entity_type: String,
+    pub entity_id: i32,
+    pub action: String,
+    pub changes: String,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = audit_log)]
+pub struct NewAuditLog {
+    pub actor_id: Option<i32>,
+    pub actor_name: String,
+    pub entity_type: String,
+    pub entity_id: i32,
+    pub action: String,
+    pub changes: String,
+    pub created_at: NaiveDateTime,
+}
+
+/// Criteria of [`AuditLog::search`], every one optional.
+#[derive(Clone, Debug, Default)]
+pub struct AuditLogFilter {
+    pub entity_type: Option<String>,
+    pub entity_id: Option<i32>,
+    pub actor_name: Option<String>,
+    pub action: Option<String>,
+    pub from: Option<NaiveDateTime>,
+    pub until: Option<NaiveDateTime>,
+}
+
+type All<DB> = diesel::dsl::Select<audit_log::table, AsSelect<AuditLog, DB>>;
+
+impl AuditLog {
+    pub fn all<DB>() -> All<DB>
+    where
+        DB: Backend,
+    {
+        audit_log::table.select(AuditLog::as_select())
+    }
+
+    /// Entries matching `filter`, newest first.
+    #[instrument(name = "AuditLog::search", skip_all)]
+    pub fn search(
+        conn: &mut SqliteConnection,
+        filter: &AuditLogFilter,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Self>> {
+        let mut query = Self::all::<Sqlite>().into_boxed();
+        if let Some(entity_type) = &filter.entity_type {
+            query = query.filter(audit_log::entity_type.eq(entity_type));
+        }
+        if let Some(entity_id) = filter.entity_id {
+            query = query.filter(audit_log::entity_id.eq(entity_id));
+        }
+        if let Some(actor_name) = &filter.actor_name {
+            query = query.filter(audit_log::actor_name.eq(actor_name));
+        }
+        if let Some(action) = &filter.action {
+            query = query.filter(audit_log::action.eq(action));
+        }
+        if let Some(from) = filter.from {
+            query = query.filter(audit_log::created_at.ge(from));
+        }
+        if let Some(until) = filter.until {
+            query = query.filter(audit_log::created_at.lt(until));
+        }
+
+        Ok(query
+            .order((audit_log::created_at.desc(), audit_log::id.desc()))
+            .limit(limit)
+            .offset(offset)
+            .load(conn)?)
+    }
+}
+
+impl NewAuditLog {
+    #[instrument(name = "NewAuditLog::save", skip_all)]
+    pub fn save(self, conn: &mut SqliteConnection) -> Result<usize> {
+        Ok(diesel::insert_into(crate::db::schema::audit_log::table)
+            .values(&self)
+            .execute(conn)?)
+    }
+}
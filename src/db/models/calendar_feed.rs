@@ -0,0 +1,71 @@
+use crate::db::schema::calendar_feed;
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use diesel::{
+    backend::Backend,
+    dsl::{AsSelect, Eq},
+    prelude::*,
+    Identifiable, Insertable, QueryDsl, Queryable, RunQueryDsl, Selectable, SelectableHelper,
+};
+use tracing::instrument;
+
+/// Secret giving calendar clients read access to one vet's `.ics` feed.
+/// Only the SHA-256 of the token is stored.
+#[derive(Clone, Debug, Eq, Identifiable, PartialEq, Queryable, Selectable)]
+#[diesel(table_name = calendar_feed)]
+pub struct CalendarFeed {
+    pub id: i32,
+    pub vet_id: i32,
+    pub token_hash: String,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = calendar_feed)]
+pub struct NewCalendarFeed {
+    pub vet_id: i32,
+    pub token_hash: String,
+    pub created_at: NaiveDateTime,
+}
+
+type All<DB> = diesel::dsl::Select<calendar_feed::table, AsSelect<CalendarFeed, DB>>;
+type WithVet = Eq<calendar_feed::vet_id, i32>;
+
+impl CalendarFeed {
+    pub fn all<DB>() -> All<DB>
+    where
+        DB: Backend,
+    {
+        calendar_feed::table.select(CalendarFeed::as_select())
+    }
+
+    fn with_vet(vet_id: i32) -> WithVet {
+        calendar_feed::vet_id.eq(vet_id)
+    }
+
+    #[instrument(name = "CalendarFeed::select_by_vet", skip_all)]
+    pub fn select_by_vet(conn: &mut SqliteConnection, vet_id: i32) -> Result<Option<Self>> {
+        Ok(crate::db::schema::calendar_feed::table
+            .filter(Self::with_vet(vet_id))
+            .get_result::<Self>(conn)
+            .optional()?)
+    }
+
+    #[instrument(name = "CalendarFeed::delete_by_vet", skip_all)]
+    pub fn delete_by_vet(conn: &mut SqliteConnection, vet_id: i32) -> Result<usize> {
+        Ok(
+            diesel::delete(crate::db::schema::calendar_feed::table.filter(Self::with_vet(vet_id)))
+                .execute(conn)?,
+        )
+    }
+}
+
+impl NewCalendarFeed {
+    #[instrument(name = "NewCalendarFeed::save", skip_all)]
+    pub fn save(self, conn: &mut SqliteConnection) -> Result<CalendarFeed> {
+        Ok(diesel::insert_into(crate::db::schema::calendar_feed::table)
+            .values(&self)
+            .returning(CalendarFeed::as_returning())
+            .get_result(conn)?)
+    }
+}
@@ -0,0 +1,68 @@
+use crate::db::schema::login_failure;
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use diesel::{
+    backend::Backend,
+    dsl::{AsSelect, Eq},
+    prelude::*,
+    Insertable, QueryDsl, Queryable, RunQueryDsl, Selectable, SelectableHelper,
+};
+use tracing::instrument;
+
+/// Failed login counter of the SQLite session store.
+#[derive(Clone, Debug, Eq, Insertable, PartialEq, Queryable, Selectable)]
+#[diesel(table_name = login_failure)]
+pub struct LoginFailure {
+    pub key: String,
+    pub count: i32,
+    pub last_failed_at: NaiveDateTime,
+    pub expires_at: NaiveDateTime,
+}
+
+type All<DB> = diesel::dsl::Select<login_failure::table, AsSelect<LoginFailure, DB>>;
+type WithKey<'a> = Eq<login_failure::key, &'a str>;
+
+impl LoginFailure {
+    pub fn all<DB>() -> All<DB>
+    where
+        DB: Backend,
+    {
+        login_failure::table.select(LoginFailure::as_select())
+    }
+
+    fn with_key(key: &str) -> WithKey<'_> {
+        login_failure::key.eq(key)
+    }
+
+    /// The counter stored under `key`, unless it expired before `now`.
+    #[instrument(name = "LoginFailure::select_live", skip_all)]
+    pub fn select_live(
+        conn: &mut SqliteConnection,
+        key: &str,
+        now: NaiveDateTime,
+    ) -> Result<Option<Self>> {
+        Ok(crate::db::schema::login_failure::table
+            .filter(Self::with_key(key))
+            .filter(login_failure::expires_at.gt(now))
+            .get_result::<Self>(conn)
+            .optional()?)
+    }
+
+    #[instrument(name = "LoginFailure::delete_by_key", skip_all)]
+    pub fn delete_by_key(conn: &mut SqliteConnection, key: &str) -> Result<usize> {
+        Ok(
+            diesel::delete(crate::db::schema::login_failure::table.filter(Self::with_key(key)))
+                .execute(conn)?,
+        )
+    }
+
+    /// Inserts the counter, replacing one with the same key.
+    #[instrument(name = "LoginFailure::save", skip_all)]
+    pub fn save(self, conn: &mut SqliteConnection) -> Result<usize> {
+        Ok(
+            diesel::replace_into(crate::db::schema::login_failure::table)
+                .values(&self)
+                .execute(conn)?,
+        )
+    }
+}
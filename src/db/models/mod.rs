@@ -1,4 +1,14 @@
+pub mod api_token;
+pub mod appointment;
+pub mod audit_log;
+pub mod calendar_feed;
+pub mod login_failure;
+pub mod owner;
+pub mod pending_login;
 pub mod pet;
+pub mod recovery_code;
+pub mod session;
 pub mod user;
+pub mod user_totp;
 pub mod vet;
 pub mod visit;
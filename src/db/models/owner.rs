@@ -0,0 +1,107 @@
+use crate::db::schema::owner;
+use anyhow::Result;
+use diesel::{
+    backend::Backend,
+    dsl::{AsSelect, Eq},
+    prelude::*,
+    AsChangeset, Identifiable, Insertable, QueryDsl, QueryResult, Queryable, RunQueryDsl,
+    Selectable, SelectableHelper,
+};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+#[derive(
+    AsChangeset,
+    Clone,
+    Debug,
+    Deserialize,
+    Eq,
+    Identifiable,
+    PartialEq,
+    Queryable,
+    Selectable,
+    Serialize,
+    Insertable,
+    Default,
+)]
+#[diesel(table_name = owner, treat_none_as_null = true)]
+pub struct Owner {
+    pub id: i32,
+    pub name: String,
+    pub phone: String,
+    pub email: Option<String>,
+    pub address: Option<String>,
+}
+
+#[derive(Serialize, Insertable)]
+#[diesel(table_name = owner)]
+pub struct NewOwner {
+    pub name: String,
+    pub phone: String,
+    pub email: Option<String>,
+    pub address: Option<String>,
+}
+
+type All<DB> = diesel::dsl::Select<owner::table, AsSelect<Owner, DB>>;
+type WithId = Eq<owner::id, i32>;
+
+impl Owner {
+    pub fn all<DB>() -> All<DB>
+    where
+        DB: Backend,
+    {
+        owner::table.select(Owner::as_select())
+    }
+
+    fn with_id(id: i32) -> WithId {
+        owner::id.eq(id)
+    }
+
+    #[instrument(name = "Owner::owners", skip_all)]
+    pub fn owners(conn: &mut SqliteConnection) -> QueryResult<Vec<Self>> {
+        Self::all().order(owner::name).load(conn)
+    }
+
+    #[instrument(name = "Owner::select_by_id", skip_all)]
+    pub fn select_by_id(conn: &mut SqliteConnection, id: i32) -> Result<Option<Self>> {
+        Ok(crate::db::schema::owner::table
+            .filter(Self::with_id(id))
+            .get_result::<Self>(conn)
+            .optional()?)
+    }
+
+    /// Owners whose name contains `name`, ignoring case.
+    #[instrument(name = "Owner::search_by_name", skip_all)]
+    pub fn search_by_name(conn: &mut SqliteConnection, name: &str) -> Result<Vec<Self>> {
+        Ok(crate::db::schema::owner::table
+            .filter(owner::name.like(format!("%{name}%")))
+            .order(owner::name)
+            .get_results::<Self>(conn)?)
+    }
+
+    #[instrument(name = "Owner::delete_by_id", skip_all)]
+    pub fn delete_by_id(conn: &mut SqliteConnection, id: i32) -> Result<usize> {
+        Ok(
+            diesel::delete(crate::db::schema::owner::table.filter(Self::with_id(id)))
+                .execute(conn)?,
+        )
+    }
+
+    #[instrument(name = "Owner::update", skip_all)]
+    pub fn update(self, conn: &mut SqliteConnection) -> Result<usize> {
+        Ok(diesel::update(crate::db::schema::owner::table)
+            .filter(Self::with_id(self.id))
+            .set(self)
+            .execute(conn)?)
+    }
+}
+
+impl NewOwner {
+    #[instrument(name = "NewOwner::save", skip_all)]
+    pub fn save(self, conn: &mut SqliteConnection) -> Result<Owner> {
+        Ok(diesel::insert_into(crate::db::schema::owner::table)
+            .values(&self)
+            .returning(Owner::as_returning())
+            .get_result(conn)?)
+    }
+}
@@ -0,0 +1,72 @@
+use crate::db::schema::pending_login;
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use diesel::{
+    backend::Backend,
+    dsl::{AsSelect, Eq},
+    prelude::*,
+    Insertable, QueryDsl, Queryable, RunQueryDsl, Selectable, SelectableHelper,
+};
+use tracing::instrument;
+
+/// Login of the SQLite session store waiting for its second factor.
+#[derive(Clone, Debug, Eq, Insertable, PartialEq, Queryable, Selectable)]
+#[diesel(table_name = pending_login)]
+pub struct PendingLogin {
+    pub key: String,
+    pub user_id: i32,
+    pub expires_at: NaiveDateTime,
+}
+
+type All<DB> = diesel::dsl::Select<pending_login::table, AsSelect<PendingLogin, DB>>;
+type WithKey<'a> = Eq<pending_login::key, &'a str>;
+
+impl PendingLogin {
+    pub fn all<DB>() -> All<DB>
+    where
+        DB: Backend,
+    {
+        pending_login::table.select(PendingLogin::as_select())
+    }
+
+    fn with_key(key: &str) -> WithKey<'_> {
+        pending_login::key.eq(key)
+    }
+
+    /// The pending login stored under `key`, unless it expired before `now`.
+    #[instrument(name = "PendingLogin::select_live", skip_all)]
+    pub fn select_live(
+        conn: &mut SqliteConnection,
+        key: &str,
+        now: NaiveDateTime,
+    ) -> Result<Option<Self>> {
+        Ok(crate::db::schema::pending_login::table
+            .filter(Self::with_key(key))
+            .filter(pending_login::expires_at.gt(now))
+            .get_result::<Self>(conn)
+            .optional()?)
+    }
+
+    #[instrument(name = "PendingLogin::delete_by_key", skip_all)]
+    pub fn delete_by_key(conn: &mut SqliteConnection, key: &str) -> Result<usize> {
+        Ok(
+            diesel::delete(crate::db::schema::pending_login::table.filter(Self::with_key(key)))
+                .execute(conn)?,
+        )
+    }
+
+    #[instrument(name = "PendingLogin::delete_expired", skip_all)]
+    pub fn delete_expired(conn: &mut SqliteConnection, now: NaiveDateTime) -> Result<usize> {
+        Ok(diesel::delete(
+            crate::db::schema::pending_login::table.filter(pending_login::expires_at.le(now)),
+        )
+        .execute(conn)?)
+    }
+
+    #[instrument(name = "PendingLogin::save", skip_all)]
+    pub fn save(self, conn: &mut SqliteConnection) -> Result<usize> {
+        Ok(diesel::insert_into(crate::db::schema::pending_login::table)
+            .values(&self)
+            .execute(conn)?)
+    }
+}
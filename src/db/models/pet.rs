@@ -9,6 +9,7 @@ use diesel::{
 };
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use tracing::instrument;
 
 #[derive(
     AsChangeset,
@@ -24,12 +25,11 @@ use std::collections::HashMap;
     Insertable,
     Default,
 )]
-#[diesel(table_name = pet)]
+#[diesel(table_name = pet, treat_none_as_null = true)]
 pub struct Pet {
     pub id: i32,
     pub name: String,
-    pub owner_name: String,
-    pub owner_phone: String,
+    pub owner_id: i32,
     pub age: i32,
     pub pet_type: i32,
     pub vet_id: Option<i32>,
@@ -41,8 +41,7 @@ pub struct Pet {
 #[diesel(table_name = pet)]
 pub struct NewPet {
     pub name: String,
-    pub owner_name: String,
-    pub owner_phone: String,
+    pub owner_id: i32,
     pub age: i32,
     pub pet_type: i32,
     pub vet_id: Option<i32>,
@@ -53,6 +52,8 @@ pub struct NewPet {
 type All<DB> = diesel::dsl::Select<pet::table, AsSelect<Pet, DB>>;
 type WithId = Eq<pet::id, i32>;
 type WithName<'a> = Eq<pet::name, &'a str>;
+type WithOwner = Eq<pet::owner_id, i32>;
+type WithVet = Eq<pet::vet_id, i32>;
 
 #[derive(Clone, Deserialize, Debug, Serialize)]
 pub enum PetType {
@@ -83,26 +84,61 @@ impl Pet {
         pet::id.eq(id)
     }
 
-    fn with_name(name: &str) -> WithName {
+    fn with_name(name: &str) -> WithName<'_> {
         pet::name.eq(name)
     }
 
+    fn with_owner(owner_id: i32) -> WithOwner {
+        pet::owner_id.eq(owner_id)
+    }
+
+    fn with_vet(vet_id: i32) -> WithVet {
+        pet::vet_id.eq(vet_id)
+    }
+
+    #[instrument(name = "Pet::pets", skip_all)]
     pub fn pets(conn: &mut SqliteConnection) -> QueryResult<Vec<Self>> {
         Self::all().load(conn)
     }
 
+    #[instrument(name = "Pet::select_by_id", skip_all)]
     pub fn select_by_id(conn: &mut SqliteConnection, id: i32) -> Result<Self> {
         Ok(crate::db::schema::pet::table
             .filter(Self::with_id(id))
             .get_result::<Self>(conn)?)
     }
 
+    #[instrument(name = "Pet::exists", skip_all)]
+    pub fn exists(conn: &mut SqliteConnection, id: i32) -> Result<bool> {
+        Ok(diesel::select(diesel::dsl::exists(
+            crate::db::schema::pet::table.filter(Self::with_id(id)),
+        ))
+        .get_result(conn)?)
+    }
+
+    #[instrument(name = "Pet::select_by_name", skip_all)]
     pub fn select_by_name(conn: &mut SqliteConnection, name: &str) -> Result<Vec<Pet>> {
         Ok(crate::db::schema::pet::table
             .filter(Self::with_name(name))
             .get_results::<Self>(conn)?)
     }
 
+    #[instrument(name = "Pet::select_by_owner", skip_all)]
+    pub fn select_by_owner(conn: &mut SqliteConnection, owner_id: i32) -> Result<Vec<Pet>> {
+        Ok(crate::db::schema::pet::table
+            .filter(Self::with_owner(owner_id))
+            .get_results::<Self>(conn)?)
+    }
+
+    /// Pets whose current vet is `vet_id`.
+    #[instrument(name = "Pet::select_by_vet", skip_all)]
+    pub fn select_by_vet(conn: &mut SqliteConnection, vet_id: i32) -> Result<Vec<Pet>> {
+        Ok(crate::db::schema::pet::table
+            .filter(Self::with_vet(vet_id))
+            .get_results::<Self>(conn)?)
+    }
+
+    #[instrument(name = "Pet::delete_by_id", skip_all)]
     pub fn delete_by_id(conn: &mut SqliteConnection, id: i32) -> Result<usize> {
         Ok(
             diesel::delete(crate::db::schema::pet::table.filter(Self::with_id(id)))
@@ -110,6 +146,7 @@ impl Pet {
         )
     }
 
+    #[instrument(name = "Pet::update", skip_all)]
     pub fn update(self, conn: &mut SqliteConnection) -> Result<usize> {
         Ok(diesel::update(crate::db::schema::pet::table)
             .filter(Self::with_id(self.id))
@@ -119,9 +156,11 @@ impl Pet {
 }
 
 impl NewPet {
-    pub fn save(self, conn: &mut SqliteConnection) -> Result<usize> {
+    #[instrument(name = "NewPet::save", skip_all)]
+    pub fn save(self, conn: &mut SqliteConnection) -> Result<Pet> {
         Ok(diesel::insert_into(crate::db::schema::pet::table)
             .values(&self)
-            .execute(conn)?)
+            .returning(Pet::as_returning())
+            .get_result(conn)?)
     }
 }
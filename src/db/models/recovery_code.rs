@@ -0,0 +1,100 @@
+use crate::db::schema::recovery_code;
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use diesel::{
+    backend::Backend,
+    dsl::{AsSelect, Eq},
+    prelude::*,
+    Identifiable, Insertable, QueryDsl, Queryable, RunQueryDsl, Selectable, SelectableHelper,
+};
+use tracing::instrument;
+
+/// Single-use code replacing a TOTP code when the authenticator app is lost.
+/// Only the SHA-256 of the code is stored.
+#[derive(Clone, Debug, Eq, Identifiable, PartialEq, Queryable, Selectable)]
+#[diesel(table_name = recovery_code)]
+pub struct RecoveryCode {
+    pub id: i32,
+    pub user_id: i32,
+    pub code_hash: String,
+    pub used_at: Option<NaiveDateTime>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = recovery_code)]
+pub struct NewRecoveryCode {
+    pub user_id: i32,
+    pub code_hash: String,
+}
+
+type All<DB> = diesel::dsl::Select<recovery_code::table, AsSelect<RecoveryCode, DB>>;
+type WithId = Eq<recovery_code::id, i32>;
+type WithUserId = Eq<recovery_code::user_id, i32>;
+
+impl RecoveryCode {
+    pub fn all<DB>() -> All<DB>
+    where
+        DB: Backend,
+    {
+        recovery_code::table.select(RecoveryCode::as_select())
+    }
+
+    fn with_id(id: i32) -> WithId {
+        recovery_code::id.eq(id)
+    }
+
+    fn with_user_id(user_id: i32) -> WithUserId {
+        recovery_code::user_id.eq(user_id)
+    }
+
+    #[instrument(name = "RecoveryCode::select_unused_by_hash", skip_all)]
+    pub fn select_unused_by_hash(
+        conn: &mut SqliteConnection,
+        user_id: i32,
+        code_hash: &str,
+    ) -> Result<Option<Self>> {
+        Ok(crate::db::schema::recovery_code::table
+            .filter(Self::with_user_id(user_id))
+            .filter(recovery_code::code_hash.eq(code_hash))
+            .filter(recovery_code::used_at.is_null())
+            .select(RecoveryCode::as_select())
+            .first::<Self>(conn)
+            .optional()?)
+    }
+
+    #[instrument(name = "RecoveryCode::count_unused", skip_all)]
+    pub fn count_unused(conn: &mut SqliteConnection, user_id: i32) -> Result<i64> {
+        Ok(crate::db::schema::recovery_code::table
+            .filter(Self::with_user_id(user_id))
+            .filter(recovery_code::used_at.is_null())
+            .count()
+            .get_result(conn)?)
+    }
+
+    /// Marks the code used, returning 0 if it already was.
+    #[instrument(name = "RecoveryCode::mark_used", skip_all)]
+    pub fn mark_used(conn: &mut SqliteConnection, id: i32, now: NaiveDateTime) -> Result<usize> {
+        Ok(diesel::update(crate::db::schema::recovery_code::table)
+            .filter(Self::with_id(id))
+            .filter(recovery_code::used_at.is_null())
+            .set(recovery_code::used_at.eq(now))
+            .execute(conn)?)
+    }
+
+    #[instrument(name = "RecoveryCode::delete_by_user_id", skip_all)]
+    pub fn delete_by_user_id(conn: &mut SqliteConnection, user_id: i32) -> Result<usize> {
+        Ok(diesel::delete(
+            crate::db::schema::recovery_code::table.filter(Self::with_user_id(user_id)),
+        )
+        .execute(conn)?)
+    }
+}
+
+impl NewRecoveryCode {
+    #[instrument(name = "NewRecoveryCode::save_all", skip_all)]
+    pub fn save_all(codes: &[Self], conn: &mut SqliteConnection) -> Result<usize> {
+        Ok(diesel::insert_into(crate::db::schema::recovery_code::table)
+            .values(codes)
+            .execute(conn)?)
+    }
+}
@@ -0,0 +1,122 @@
+use crate::db::schema::session;
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use diesel::{
+    backend::Backend,
+    dsl::{AsSelect, Eq},
+    prelude::*,
+    Identifiable, Insertable, QueryDsl, Queryable, RunQueryDsl, Selectable, SelectableHelper,
+};
+use tracing::instrument;
+
+/// Login session of the SQLite session store. `data` holds the JSON of the
+/// logged in [`User`](super::user::User).
+#[derive(Clone, Debug, Eq, Identifiable, Insertable, PartialEq, Queryable, Selectable)]
+#[diesel(table_name = session, primary_key(key))]
+pub struct Session {
+    pub key: String,
+    pub user_id: i32,
+    pub data: String,
+    pub created_at: NaiveDateTime,
+    pub expires_at: NaiveDateTime,
+    pub last_seen_at: NaiveDateTime,
+    pub ip: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+type All<DB> = diesel::dsl::Select<session::table, AsSelect<Session, DB>>;
+type WithKey<'a> = Eq<session::key, &'a str>;
+type WithUserId = Eq<session::user_id, i32>;
+
+impl Session {
+    pub fn all<DB>() -> All<DB>
+    where
+        DB: Backend,
+    {
+        session::table.select(Session::as_select())
+    }
+
+    fn with_key(key: &str) -> WithKey<'_> {
+        session::key.eq(key)
+    }
+
+    fn with_user_id(user_id: i32) -> WithUserId {
+        session::user_id.eq(user_id)
+    }
+
+    /// The session stored under `key`, unless it expired before `now`.
+    #[instrument(name = "Session::select_live", skip_all)]
+    pub fn select_live(
+        conn: &mut SqliteConnection,
+        key: &str,
+        now: NaiveDateTime,
+    ) -> Result<Option<Self>> {
+        Ok(crate::db::schema::session::table
+            .filter(Self::with_key(key))
+            .filter(session::expires_at.gt(now))
+            .get_result::<Self>(conn)
+            .optional()?)
+    }
+
+    #[instrument(name = "Session::select_live_by_user_id", skip_all)]
+    pub fn select_live_by_user_id(
+        conn: &mut SqliteConnection,
+        user_id: i32,
+        now: NaiveDateTime,
+    ) -> Result<Vec<Self>> {
+        Ok(crate::db::schema::session::table
+            .filter(Self::with_user_id(user_id))
+            .filter(session::expires_at.gt(now))
+            .order(session::last_seen_at.desc())
+            .load::<Self>(conn)?)
+    }
+
+    /// Records the session as used at `now`, extending it until `expires_at`.
+    #[instrument(name = "Session::touch", skip_all)]
+    pub fn touch(
+        conn: &mut SqliteConnection,
+        key: &str,
+        now: NaiveDateTime,
+        expires_at: NaiveDateTime,
+    ) -> Result<usize> {
+        Ok(diesel::update(crate::db::schema::session::table)
+            .filter(Self::with_key(key))
+            .set((
+                session::last_seen_at.eq(now),
+                session::expires_at.eq(expires_at),
+            ))
+            .execute(conn)?)
+    }
+
+    #[instrument(name = "Session::delete_by_key", skip_all)]
+    pub fn delete_by_key(conn: &mut SqliteConnection, key: &str) -> Result<usize> {
+        Ok(
+            diesel::delete(crate::db::schema::session::table.filter(Self::with_key(key)))
+                .execute(conn)?,
+        )
+    }
+
+    #[instrument(name = "Session::delete_by_user_id", skip_all)]
+    pub fn delete_by_user_id(conn: &mut SqliteConnection, user_id: i32) -> Result<usize> {
+        Ok(
+            diesel::delete(crate::db::schema::session::table.filter(Self::with_user_id(user_id)))
+                .execute(conn)?,
+        )
+    }
+
+    #[instrument(name = "Session::delete_expired", skip_all)]
+    pub fn delete_expired(conn: &mut SqliteConnection, now: NaiveDateTime) -> Result<usize> {
+        Ok(
+            diesel::delete(crate::db::schema::session::table.filter(session::expires_at.le(now)))
+                .execute(conn)?,
+        )
+    }
+
+    /// Inserts the session, replacing one with the same key.
+    #[instrument(name = "Session::save", skip_all)]
+    pub fn save(self, conn: &mut SqliteConnection) -> Result<usize> {
+        Ok(diesel::replace_into(crate::db::schema::session::table)
+            .values(&self)
+            .execute(conn)?)
+    }
+}
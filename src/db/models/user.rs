@@ -2,12 +2,78 @@ use crate::db::schema::user;
 use anyhow::Result;
 use diesel::{
     backend::Backend,
+    deserialize::{self, FromSql, FromSqlRow},
     dsl::{AsSelect, Eq},
-    AsChangeset, ExpressionMethods, Identifiable, QueryDsl, QueryResult, Queryable,
-    QueryableByName, RunQueryDsl, Selectable, SelectableHelper, SqliteConnection,
+    expression::AsExpression,
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::Text,
+    sqlite::{Sqlite, SqliteValue},
+    AsChangeset, ExpressionMethods, Identifiable, Insertable, OptionalExtension, QueryDsl,
+    QueryResult, Queryable, QueryableByName, RunQueryDsl, Selectable, SelectableHelper,
+    SqliteConnection,
 };
 use redis::{ErrorKind, FromRedisValue};
 use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+use tracing::instrument;
+
+/// Role of a staff account, deciding which actions the user may perform.
+#[derive(
+    AsExpression, Clone, Copy, Debug, Default, Deserialize, Eq, FromSqlRow, PartialEq, Serialize,
+)]
+#[diesel(sql_type = Text)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    Admin,
+    Vet,
+    Receptionist,
+    #[default]
+    ReadOnly,
+}
+
+impl Role {
+    pub const ALL: [Role; 4] = [Role::Admin, Role::Vet, Role::Receptionist, Role::ReadOnly];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Admin => "admin",
+            Role::Vet => "vet",
+            Role::Receptionist => "receptionist",
+            Role::ReadOnly => "read_only",
+        }
+    }
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl FromStr for Role {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Role::ALL
+            .into_iter()
+            .find(|role| role.as_str() == s)
+            .ok_or_else(|| anyhow::anyhow!("Unknown role: {s}"))
+    }
+}
+
+impl ToSql<Text, Sqlite> for Role {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        out.set_value(self.as_str());
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<Text, Sqlite> for Role {
+    fn from_sql(bytes: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+        let s = <String as FromSql<Text, Sqlite>>::from_sql(bytes)?;
+        Ok(s.parse()?)
+    }
+}
 
 #[derive(
     AsChangeset,
@@ -25,11 +91,26 @@ use serde::{Deserialize, Serialize};
 #[diesel(table_name = user)]
 pub struct User {
     pub id: i32,
+    pub username: String,
+    /// Argon2id PHC string, never written to the session store
+    #[serde(default, skip_serializing)]
+    pub password: String,
+    #[serde(default)]
+    pub disabled: bool,
+    #[serde(default)]
+    pub role: Role,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = user)]
+pub struct NewUser {
     pub username: String,
     pub password: String,
+    pub role: Role,
 }
 
 type All<DB> = diesel::dsl::Select<user::table, AsSelect<User, DB>>;
+type WithId = Eq<user::id, i32>;
 type WithName<'a> = Eq<user::username, &'a str>;
 
 impl User {
@@ -40,19 +121,70 @@ impl User {
         user::table.select(User::as_select())
     }
 
-    pub fn with_name(name: &str) -> WithName {
+    fn with_id(id: i32) -> WithId {
+        user::id.eq(id)
+    }
+
+    pub fn with_name(name: &str) -> WithName<'_> {
         user::username.eq(name)
     }
 
+    #[instrument(name = "User::users", skip_all)]
     pub fn users(conn: &mut SqliteConnection) -> QueryResult<Vec<Self>> {
         Self::all().load(conn)
     }
 
+    #[instrument(name = "User::select_by_id", skip_all)]
+    pub fn select_by_id(conn: &mut SqliteConnection, id: i32) -> Result<Option<Self>> {
+        Ok(crate::db::schema::user::table
+            .filter(Self::with_id(id))
+            .get_result::<Self>(conn)
+            .optional()?)
+    }
+
+    #[instrument(name = "User::select_by_name", skip_all)]
     pub fn select_by_name(conn: &mut SqliteConnection, name: &str) -> Result<Self> {
         Ok(crate::db::schema::user::table
             .filter(Self::with_name(name))
             .get_result::<Self>(conn)?)
     }
+
+    #[instrument(name = "User::set_role", skip_all)]
+    pub fn set_role(conn: &mut SqliteConnection, id: i32, role: Role) -> Result<usize> {
+        Ok(diesel::update(crate::db::schema::user::table)
+            .filter(Self::with_id(id))
+            .set(user::role.eq(role))
+            .execute(conn)?)
+    }
+
+    #[instrument(name = "User::set_disabled", skip_all)]
+    pub fn set_disabled(conn: &mut SqliteConnection, id: i32, disabled: bool) -> Result<usize> {
+        Ok(diesel::update(crate::db::schema::user::table)
+            .filter(Self::with_id(id))
+            .set(user::disabled.eq(disabled))
+            .execute(conn)?)
+    }
+
+    #[instrument(name = "User::update_password", skip_all)]
+    pub fn update_password(
+        conn: &mut SqliteConnection,
+        id: i32,
+        password_hash: &str,
+    ) -> Result<usize> {
+        Ok(diesel::update(crate::db::schema::user::table)
+            .filter(Self::with_id(id))
+            .set(user::password.eq(password_hash))
+            .execute(conn)?)
+    }
+}
+
+impl NewUser {
+    #[instrument(name = "NewUser::save", skip_all)]
+    pub fn save(self, conn: &mut SqliteConnection) -> Result<usize> {
+        Ok(diesel::insert_into(crate::db::schema::user::table)
+            .values(&self)
+            .execute(conn)?)
+    }
 }
 
 impl FromRedisValue for User {
@@ -0,0 +1,96 @@
+use crate::db::schema::user_totp;
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use diesel::{
+    backend::Backend,
+    dsl::{AsSelect, Eq},
+    prelude::*,
+    Identifiable, Insertable, QueryDsl, QueryResult, Queryable, RunQueryDsl, Selectable,
+    SelectableHelper,
+};
+use tracing::instrument;
+
+/// TOTP secret of a user, base32 encoded. Two-factor login is only required
+/// once `enabled`, after the user confirmed a first code from the app.
+#[derive(Clone, Debug, Eq, Identifiable, Insertable, PartialEq, Queryable, Selectable)]
+#[diesel(table_name = user_totp, primary_key(user_id))]
+pub struct UserTotp {
+    pub user_id: i32,
+    pub secret: String,
+    pub enabled: bool,
+    /// Time step of the last accepted code, which cannot be used again
+    pub last_used_step: Option<i64>,
+    pub created_at: NaiveDateTime,
+}
+
+type All<DB> = diesel::dsl::Select<user_totp::table, AsSelect<UserTotp, DB>>;
+type WithUserId = Eq<user_totp::user_id, i32>;
+
+impl UserTotp {
+    pub fn all<DB>() -> All<DB>
+    where
+        DB: Backend,
+    {
+        user_totp::table.select(UserTotp::as_select())
+    }
+
+    fn with_user_id(user_id: i32) -> WithUserId {
+        user_totp::user_id.eq(user_id)
+    }
+
+    #[instrument(name = "UserTotp::select_by_user_id", skip_all)]
+    pub fn select_by_user_id(conn: &mut SqliteConnection, user_id: i32) -> Result<Option<Self>> {
+        Ok(crate::db::schema::user_totp::table
+            .filter(Self::with_user_id(user_id))
+            .get_result::<Self>(conn)
+            .optional()?)
+    }
+
+    /// Ids of the users who have to enter a code when logging in.
+    #[instrument(name = "UserTotp::enabled_user_ids", skip_all)]
+    pub fn enabled_user_ids(conn: &mut SqliteConnection) -> QueryResult<Vec<i32>> {
+        crate::db::schema::user_totp::table
+            .filter(user_totp::enabled.eq(true))
+            .select(user_totp::user_id)
+            .load(conn)
+    }
+
+    #[instrument(name = "UserTotp::enable", skip_all)]
+    pub fn enable(conn: &mut SqliteConnection, user_id: i32, step: i64) -> Result<usize> {
+        Ok(diesel::update(crate::db::schema::user_totp::table)
+            .filter(Self::with_user_id(user_id))
+            .set((
+                user_totp::enabled.eq(true),
+                user_totp::last_used_step.eq(step),
+            ))
+            .execute(conn)?)
+    }
+
+    #[instrument(name = "UserTotp::set_last_used_step", skip_all)]
+    pub fn set_last_used_step(
+        conn: &mut SqliteConnection,
+        user_id: i32,
+        step: i64,
+    ) -> Result<usize> {
+        Ok(diesel::update(crate::db::schema::user_totp::table)
+            .filter(Self::with_user_id(user_id))
+            .set(user_totp::last_used_step.eq(step))
+            .execute(conn)?)
+    }
+
+    #[instrument(name = "UserTotp::delete_by_user_id", skip_all)]
+    pub fn delete_by_user_id(conn: &mut SqliteConnection, user_id: i32) -> Result<usize> {
+        Ok(
+            diesel::delete(crate::db::schema::user_totp::table.filter(Self::with_user_id(user_id)))
+                .execute(conn)?,
+        )
+    }
+
+    /// Inserts the secret, replacing an unfinished enrollment of the same user.
+    #[instrument(name = "UserTotp::save", skip_all)]
+    pub fn save(self, conn: &mut SqliteConnection) -> Result<usize> {
+        Ok(diesel::replace_into(crate::db::schema::user_totp::table)
+            .values(&self)
+            .execute(conn)?)
+    }
+}
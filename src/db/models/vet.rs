@@ -8,6 +8,7 @@ use diesel::{
     RunQueryDsl, Selectable, SelectableHelper,
 };
 use serde::{Deserialize, Serialize};
+use tracing::instrument;
 
 #[derive(
     AsChangeset,
@@ -52,14 +53,16 @@ impl Vet {
         vet::id.eq(id)
     }
 
-    fn with_name(name: &str) -> WithName {
+    fn with_name(name: &str) -> WithName<'_> {
         vet::name.eq(name)
     }
 
+    #[instrument(name = "Vet::vets", skip_all)]
     pub fn vets(conn: &mut SqliteConnection) -> QueryResult<Vec<Self>> {
         Self::all().load(conn)
     }
 
+    #[instrument(name = "Vet::select_by_id", skip_all)]
     pub fn select_by_id(conn: &mut SqliteConnection, id: i32) -> Result<Option<Self>> {
         Ok(crate::db::schema::vet::table
             .filter(Self::with_id(id))
@@ -67,12 +70,14 @@ impl Vet {
             .optional()?)
     }
 
+    #[instrument(name = "Vet::select_by_name", skip_all)]
     pub fn select_by_name(conn: &mut SqliteConnection, name: &str) -> Result<Vec<Self>> {
         Ok(crate::db::schema::vet::table
             .filter(Self::with_name(name))
             .get_results::<Self>(conn)?)
     }
 
+    #[instrument(name = "Vet::delete_by_id", skip_all)]
     pub fn delete_by_id(conn: &mut SqliteConnection, id: i32) -> Result<usize> {
         Ok(
             diesel::delete(crate::db::schema::vet::table.filter(Self::with_id(id)))
@@ -80,6 +85,7 @@ impl Vet {
         )
     }
 
+    #[instrument(name = "Vet::update", skip_all)]
     pub fn update(self, conn: &mut SqliteConnection) -> Result<usize> {
         Ok(diesel::update(crate::db::schema::vet::table)
             .filter(Self::with_id(self.id))
@@ -89,9 +95,11 @@ impl Vet {
 }
 
 impl NewVet {
-    pub fn save(self, conn: &mut SqliteConnection) -> Result<usize> {
+    #[instrument(name = "NewVet::save", skip_all)]
+    pub fn save(self, conn: &mut SqliteConnection) -> Result<Vet> {
         Ok(diesel::insert_into(crate::db::schema::vet::table)
             .values(&self)
-            .execute(conn)?)
+            .returning(Vet::as_returning())
+            .get_result(conn)?)
     }
 }
@@ -1,13 +1,125 @@
 use crate::db::schema::visit;
-use chrono::NaiveDateTime;
-use diesel::prelude::*;
+use anyhow::Result;
+use chrono::NaiveDate;
+use diesel::{
+    backend::Backend,
+    dsl::{AsSelect, Eq},
+    prelude::*,
+    AsChangeset, Identifiable, Insertable, QueryDsl, QueryResult, Queryable, RunQueryDsl,
+    Selectable, SelectableHelper,
+};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
 
-#[derive(Queryable, Identifiable, Selectable, Debug, PartialEq, Eq)]
+#[derive(
+    AsChangeset,
+    Clone,
+    Debug,
+    Deserialize,
+    Eq,
+    Identifiable,
+    PartialEq,
+    Queryable,
+    Selectable,
+    Serialize,
+    Insertable,
+    Default,
+)]
 #[diesel(table_name = visit)]
 pub struct Visit {
-    pub id: Option<i32>,
+    pub id: i32,
     pub pet_id: i32,
     pub vet_id: i32,
-    pub visit_date: NaiveDateTime,
+    pub visit_date: NaiveDate,
     pub notes: Option<String>,
 }
+
+#[derive(Serialize, Insertable)]
+#[diesel(table_name = visit)]
+pub struct NewVisit {
+    pub pet_id: i32,
+    pub vet_id: i32,
+    pub visit_date: NaiveDate,
+    pub notes: Option<String>,
+}
+
+type All<DB> = diesel::dsl::Select<visit::table, AsSelect<Visit, DB>>;
+type WithId = Eq<visit::id, i32>;
+type WithPet = Eq<visit::pet_id, i32>;
+type WithVet = Eq<visit::vet_id, i32>;
+
+impl Visit {
+    pub fn all<DB>() -> All<DB>
+    where
+        DB: Backend,
+    {
+        visit::table.select(Visit::as_select())
+    }
+
+    fn with_id(id: i32) -> WithId {
+        visit::id.eq(id)
+    }
+
+    fn with_pet(pet_id: i32) -> WithPet {
+        visit::pet_id.eq(pet_id)
+    }
+
+    fn with_vet(vet_id: i32) -> WithVet {
+        visit::vet_id.eq(vet_id)
+    }
+
+    #[instrument(name = "Visit::visits", skip_all)]
+    pub fn visits(conn: &mut SqliteConnection) -> QueryResult<Vec<Self>> {
+        Self::all().load(conn)
+    }
+
+    #[instrument(name = "Visit::select_by_id", skip_all)]
+    pub fn select_by_id(conn: &mut SqliteConnection, id: i32) -> Result<Option<Self>> {
+        Ok(crate::db::schema::visit::table
+            .filter(Self::with_id(id))
+            .get_result::<Self>(conn)
+            .optional()?)
+    }
+
+    #[instrument(name = "Visit::select_by_pet", skip_all)]
+    pub fn select_by_pet(conn: &mut SqliteConnection, pet_id: i32) -> Result<Vec<Self>> {
+        Ok(crate::db::schema::visit::table
+            .filter(Self::with_pet(pet_id))
+            .order(visit::visit_date.desc())
+            .get_results::<Self>(conn)?)
+    }
+
+    #[instrument(name = "Visit::select_by_vet", skip_all)]
+    pub fn select_by_vet(conn: &mut SqliteConnection, vet_id: i32) -> Result<Vec<Self>> {
+        Ok(crate::db::schema::visit::table
+            .filter(Self::with_vet(vet_id))
+            .order(visit::visit_date.desc())
+            .get_results::<Self>(conn)?)
+    }
+
+    #[instrument(name = "Visit::delete_by_id", skip_all)]
+    pub fn delete_by_id(conn: &mut SqliteConnection, id: i32) -> Result<usize> {
+        Ok(
+            diesel::delete(crate::db::schema::visit::table.filter(Self::with_id(id)))
+                .execute(conn)?,
+        )
+    }
+
+    #[instrument(name = "Visit::update", skip_all)]
+    pub fn update(self, conn: &mut SqliteConnection) -> Result<usize> {
+        Ok(diesel::update(crate::db::schema::visit::table)
+            .filter(Self::with_id(self.id))
+            .set(self)
+            .execute(conn)?)
+    }
+}
+
+impl NewVisit {
+    #[instrument(name = "NewVisit::save", skip_all)]
+    pub fn save(self, conn: &mut SqliteConnection) -> Result<Visit> {
+        Ok(diesel::insert_into(crate::db::schema::visit::table)
+            .values(&self)
+            .returning(Visit::as_returning())
+            .get_result(conn)?)
+    }
+}
@@ -1,11 +1,85 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    api_token (id) {
+        id -> Integer,
+        user_id -> Integer,
+        name -> Text,
+        token_hash -> Text,
+        created_at -> Timestamp,
+        last_used_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    appointment (id) {
+        id -> Integer,
+        pet_id -> Integer,
+        vet_id -> Integer,
+        starts_at -> Timestamp,
+        ends_at -> Timestamp,
+        status -> Text,
+        reason -> Nullable<Text>,
+        created_at -> Timestamp,
+        created_by -> Integer,
+        sequence -> Integer,
+    }
+}
+
+diesel::table! {
+    audit_log (id) {
+        id -> Integer,
+        actor_id -> Nullable<Integer>,
+        actor_name -> Text,
+        entity_type -> Text,
+        entity_id -> Integer,
+        action -> Text,
+        changes -> Text,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    calendar_feed (id) {
+        id -> Integer,
+        vet_id -> Integer,
+        token_hash -> Text,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    login_failure (key) {
+        key -> Text,
+        count -> Integer,
+        last_failed_at -> Timestamp,
+        expires_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    owner (id) {
+        id -> Integer,
+        name -> Text,
+        phone -> Text,
+        email -> Nullable<Text>,
+        address -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    pending_login (key) {
+        key -> Text,
+        user_id -> Integer,
+        expires_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     pet (id) {
         id -> Integer,
         name -> Text,
-        owner_name -> Text,
-        owner_phone -> Text,
+        owner_id -> Integer,
         age -> Integer,
         pet_type -> Integer,
         vet_id -> Nullable<Integer>,
@@ -14,11 +88,45 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    recovery_code (id) {
+        id -> Integer,
+        user_id -> Integer,
+        code_hash -> Text,
+        used_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    session (key) {
+        key -> Text,
+        user_id -> Integer,
+        data -> Text,
+        created_at -> Timestamp,
+        expires_at -> Timestamp,
+        last_seen_at -> Timestamp,
+        ip -> Nullable<Text>,
+        user_agent -> Nullable<Text>,
+    }
+}
+
 diesel::table! {
     user (id) {
         id -> Integer,
         username -> Text,
         password -> Text,
+        disabled -> Bool,
+        role -> Text,
+    }
+}
+
+diesel::table! {
+    user_totp (user_id) {
+        user_id -> Integer,
+        secret -> Text,
+        enabled -> Bool,
+        last_used_step -> Nullable<BigInt>,
+        created_at -> Timestamp,
     }
 }
 
@@ -39,9 +147,34 @@ diesel::table! {
     }
 }
 
+diesel::joinable!(api_token -> user (user_id));
+diesel::joinable!(appointment -> pet (pet_id));
+diesel::joinable!(appointment -> user (created_by));
+diesel::joinable!(appointment -> vet (vet_id));
+diesel::joinable!(calendar_feed -> vet (vet_id));
+diesel::joinable!(pending_login -> user (user_id));
+diesel::joinable!(pet -> owner (owner_id));
 diesel::joinable!(pet -> user (created_by));
 diesel::joinable!(pet -> vet (vet_id));
+diesel::joinable!(recovery_code -> user (user_id));
+diesel::joinable!(session -> user (user_id));
+diesel::joinable!(user_totp -> user (user_id));
 diesel::joinable!(visit -> pet (pet_id));
 diesel::joinable!(visit -> vet (vet_id));
 
-diesel::allow_tables_to_appear_in_same_query!(pet, user, vet, visit,);
+diesel::allow_tables_to_appear_in_same_query!(
+    api_token,
+    appointment,
+    audit_log,
+    calendar_feed,
+    login_failure,
+    owner,
+    pending_login,
+    pet,
+    recovery_code,
+    session,
+    user,
+    user_totp,
+    vet,
+    visit,
+);
@@ -0,0 +1,173 @@
+//! Errors of the HTML handlers and the pages showing them.
+//!
+//! [`AppError`] only decides the status and the message meant for the user,
+//! [`pages`] then renders that as `templates/errors/<status>.html`, or as JSON
+//! for clients asking for it. Details of internal errors are only logged.
+
+use crate::{logging, logic::validation::FieldErrors, metrics};
+use axum::{
+    extract::{Extension, Request},
+    http::{header, HeaderMap, StatusCode},
+    middleware::Next,
+    response::{Html, IntoResponse, Response},
+    Json,
+};
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+use serde_json::json;
+use std::{fmt, sync::Arc};
+use tera::Tera;
+
+#[derive(Debug)]
+pub enum AppError {
+    NotFound,
+    /// The request itself is wrong, such as an unparsable form.
+    Validation(String),
+    Unauthorized,
+    Forbidden,
+    /// The change clashes with the current state, such as a taken username.
+    Conflict(String),
+    Internal(anyhow::Error),
+}
+
+impl AppError {
+    pub fn status(&self) -> StatusCode {
+        match self {
+            AppError::NotFound => StatusCode::NOT_FOUND,
+            AppError::Validation(_) => StatusCode::BAD_REQUEST,
+            AppError::Unauthorized => StatusCode::UNAUTHORIZED,
+            AppError::Forbidden => StatusCode::FORBIDDEN,
+            AppError::Conflict(_) => StatusCode::CONFLICT,
+            AppError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    /// What the user is told, never the internal error.
+    fn message(&self) -> String {
+        match self {
+            AppError::NotFound => "The page you are looking for does not exist".to_string(),
+            AppError::Validation(message) | AppError::Conflict(message) => message.clone(),
+            AppError::Unauthorized => "Please log in to continue".to_string(),
+            AppError::Forbidden => "You are not allowed to perform this action".to_string(),
+            AppError::Internal(_) => "Something went wrong on our side".to_string(),
+        }
+    }
+}
+
+/// Left on the response by [`AppError`] for [`pages`] to render.
+#[derive(Clone)]
+struct ErrorPage {
+    message: String,
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        if let AppError::Internal(e) = &self {
+            tracing::error!("Request failed: {e:?}");
+        }
+
+        let status = self.status();
+        let message = self.message();
+        let mut response = (status, message.clone()).into_response();
+        response.extensions_mut().insert(ErrorPage { message });
+        response
+    }
+}
+
+impl<E> From<E> for AppError
+where
+    E: Into<anyhow::Error>,
+{
+    fn from(e: E) -> Self {
+        let e = e.into();
+        if let Some(e) = e.downcast_ref::<UserError>() {
+            return match e {
+                UserError::NotFound => AppError::NotFound,
+                UserError::Validation(message) => AppError::Validation(message.clone()),
+                UserError::Conflict(message) => AppError::Conflict(message.clone()),
+            };
+        }
+        if let Some(errors) = e.downcast_ref::<FieldErrors>() {
+            return AppError::Validation(errors.to_string());
+        }
+        match e.downcast_ref::<DieselError>() {
+            Some(DieselError::NotFound) => AppError::NotFound,
+            Some(DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _)) => {
+                AppError::Conflict("This already exists".to_string())
+            }
+            _ => AppError::Internal(e),
+        }
+    }
+}
+
+/// An error the user can act on, for code returning `anyhow::Result` such as
+/// `interact` closures and `logic`. It turns into the matching [`AppError`].
+#[derive(Debug)]
+pub enum UserError {
+    NotFound,
+    Validation(String),
+    Conflict(String),
+}
+
+impl fmt::Display for UserError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UserError::NotFound => f.write_str("Not found"),
+            UserError::Validation(message) | UserError::Conflict(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for UserError {}
+
+/// Templates of the error pages. Kept apart from the per request [`Tera`],
+/// which only exists for routes and not for the fallback.
+#[derive(Clone)]
+pub struct ErrorPages(pub Arc<Tera>);
+
+/// Renders the [`AppError`] a handler returned as a page, or as
+/// `{"error": "...", "request_id": "..."}` if the client prefers JSON.
+pub async fn pages(
+    Extension(ErrorPages(tera)): Extension<ErrorPages>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let json = prefers_json(request.headers());
+    let mut response = next.run(request).await;
+    let Some(page) = response.extensions_mut().remove::<ErrorPage>() else {
+        return response;
+    };
+
+    let status = response.status();
+    let request_id = logging::current_request_id();
+    if json {
+        let body = json!({ "error": page.message, "request_id": request_id });
+        return (status, Json(body)).into_response();
+    }
+
+    let mut c = tera::Context::new();
+    c.insert("status", &status.as_u16());
+    c.insert("reason", &status.canonical_reason().unwrap_or_default());
+    c.insert("message", &page.message);
+    c.insert("request_id", &request_id);
+    let template = format!("errors/{}.html", status.as_u16());
+    match metrics::render(&tera, &template, &c) {
+        Ok(html) => (status, Html(html)).into_response(),
+        Err(e) => {
+            tracing::error!("Cannot render error page: {e:?}");
+            response
+        }
+    }
+}
+
+/// Browsers list `text/html` first, API clients ask for `application/json`.
+pub fn prefers_json(headers: &HeaderMap) -> bool {
+    let accept = headers
+        .get(header::ACCEPT)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+    match (accept.find("application/json"), accept.find("text/html")) {
+        (Some(json), Some(html)) => json < html,
+        (Some(_), None) => true,
+        _ => false,
+    }
+}
@@ -0,0 +1,271 @@
+//! JSON API mounted under `/api/v1`, authenticated with bearer tokens
+//! instead of the cookie session used by the HTML pages.
+
+use crate::{
+    db::models::user::User,
+    logging,
+    logic::{api_tokens, permissions::Permission, validation::FieldErrors},
+    AppError, Context,
+};
+use axum::{
+    async_trait,
+    extract::{FromRequest, FromRequestParts, Path, Request},
+    http::{header, request::Parts, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use serde::de::DeserializeOwned;
+use serde_json::json;
+use std::sync::Arc;
+
+pub mod owners;
+pub mod pets;
+pub mod vets;
+pub mod visits;
+
+pub fn routes() -> Router {
+    Router::new()
+        .route("/pets", get(pets::list).post(pets::create))
+        .route(
+            "/pets/:id",
+            get(pets::get).put(pets::update).delete(pets::delete),
+        )
+        .route(
+            "/pets/:id/visits",
+            get(visits::list_by_pet).post(visits::create),
+        )
+        .route("/vets", get(vets::list).post(vets::create))
+        .route(
+            "/vets/:id",
+            get(vets::get).put(vets::update).delete(vets::delete),
+        )
+        .route("/vets/:id/visits", get(visits::list_by_vet))
+        .route(
+            "/visits/:id",
+            get(visits::get).put(visits::update).delete(visits::delete),
+        )
+        .route("/owners", get(owners::list).post(owners::create))
+        .route(
+            "/owners/:id",
+            get(owners::get).put(owners::update).delete(owners::delete),
+        )
+        .route("/owners/:id/pets", get(owners::pets))
+        .fallback(|| async { ApiError::NotFound })
+}
+
+/// Error returned by API handlers, rendered as
+/// `{"error": "...", "request_id": "..."}` with a matching status code.
+/// Internal errors are logged and not exposed.
+#[derive(Debug)]
+pub enum ApiError {
+    BadRequest(String),
+    /// Input that parsed but failed validation, reported per field.
+    Invalid(FieldErrors),
+    Unauthorized,
+    Forbidden,
+    NotFound,
+    Conflict(String),
+    Internal(anyhow::Error),
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let request_id = logging::current_request_id();
+        let (status, message) = match self {
+            ApiError::BadRequest(message) => (StatusCode::BAD_REQUEST, message),
+            ApiError::Invalid(errors) => {
+                let body = json!({
+                    "error": "Invalid input",
+                    "fields": errors,
+                    "request_id": request_id,
+                });
+                return (StatusCode::UNPROCESSABLE_ENTITY, Json(body)).into_response();
+            }
+            ApiError::Unauthorized => (
+                StatusCode::UNAUTHORIZED,
+                "Missing or invalid bearer token".to_string(),
+            ),
+            ApiError::Forbidden => (
+                StatusCode::FORBIDDEN,
+                "You are not allowed to perform this action".to_string(),
+            ),
+            ApiError::NotFound => (StatusCode::NOT_FOUND, "Not found".to_string()),
+            ApiError::Conflict(message) => (StatusCode::CONFLICT, message),
+            ApiError::Internal(e) => {
+                tracing::error!("API request failed: {e:?}");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Internal server error".to_string(),
+                )
+            }
+        };
+
+        let body = Json(json!({ "error": message, "request_id": request_id }));
+        if status == StatusCode::UNAUTHORIZED {
+            (status, [(header::WWW_AUTHENTICATE, "Bearer")], body).into_response()
+        } else {
+            (status, body).into_response()
+        }
+    }
+}
+
+impl<E> From<E> for ApiError
+where
+    E: Into<anyhow::Error>,
+{
+    fn from(e: E) -> Self {
+        let e = e.into();
+        let e = match e.downcast::<FieldErrors>() {
+            Ok(errors) => return ApiError::Invalid(errors),
+            Err(e) => e,
+        };
+        // Everything else is classified like for the HTML pages
+        match AppError::from(e) {
+            AppError::NotFound => ApiError::NotFound,
+            AppError::Validation(message) => ApiError::BadRequest(message),
+            AppError::Unauthorized => ApiError::Unauthorized,
+            AppError::Forbidden => ApiError::Forbidden,
+            AppError::Conflict(message) => ApiError::Conflict(message),
+            AppError::Internal(e) => ApiError::Internal(e),
+        }
+    }
+}
+
+/// Runs `f` on a pooled SQLite connection, mapping every failure to [`ApiError`].
+pub async fn interact<F, R>(ctx: &Context, f: F) -> Result<R, ApiError>
+where
+    F: FnOnce(&mut diesel::SqliteConnection) -> anyhow::Result<R> + Send + 'static,
+    R: Send + 'static,
+{
+    let db_conn = ctx.db_connection().await?;
+    db_conn
+        .interact(f)
+        .await
+        .map_err(|e| ApiError::Internal(anyhow::Error::msg(e.to_string())))?
+        .map_err(ApiError::from)
+}
+
+/// The user owning the request's `Authorization: Bearer` token.
+pub struct ApiUser(pub User);
+
+impl ApiUser {
+    pub fn require(&self, permission: Permission) -> Result<(), ApiError> {
+        if self.0.role.can(permission) {
+            Ok(())
+        } else {
+            Err(ApiError::Forbidden)
+        }
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for ApiUser
+where
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        let ctx = Arc::clone(
+            parts
+                .extensions
+                .get::<Arc<Context>>()
+                .ok_or_else(|| ApiError::Internal(anyhow::Error::msg("Context missing")))?,
+        );
+
+        let token = parts
+            .headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(|token| token.trim().to_string())
+            .ok_or(ApiError::Unauthorized)?;
+
+        let user = interact(&ctx, move |conn| {
+            api_tokens::authenticate_token(conn, &token)
+        })
+        .await?
+        .ok_or(ApiError::Unauthorized)?;
+        logging::record_user(&user.username);
+        Ok(ApiUser(user))
+    }
+}
+
+/// `Json` extractor whose rejection is a JSON [`ApiError`].
+pub struct ApiJson<T>(pub T);
+
+#[async_trait]
+impl<S, T> FromRequest<S> for ApiJson<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned,
+{
+    type Rejection = ApiError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        Json::<T>::from_request(req, state)
+            .await
+            .map(|Json(value)| ApiJson(value))
+            .map_err(|rejection| ApiError::BadRequest(rejection.body_text()))
+    }
+}
+
+/// `Path` extractor whose rejection is a JSON [`ApiError`].
+pub struct ApiPath<T>(pub T);
+
+#[async_trait]
+impl<S, T> FromRequestParts<S> for ApiPath<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned + Send,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        Path::<T>::from_request_parts(parts, state)
+            .await
+            .map(|Path(value)| ApiPath(value))
+            .map_err(|rejection| ApiError::BadRequest(rejection.body_text()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::UserError;
+
+    #[test]
+    fn errors_map_like_app_errors() {
+        assert!(matches!(
+            ApiError::from(UserError::NotFound),
+            ApiError::NotFound
+        ));
+        assert!(matches!(
+            ApiError::from(UserError::Validation("bad".to_string())),
+            ApiError::BadRequest(message) if message == "bad"
+        ));
+        assert!(matches!(
+            ApiError::from(UserError::Conflict("taken".to_string())),
+            ApiError::Conflict(message) if message == "taken"
+        ));
+        assert!(matches!(
+            ApiError::from(diesel::result::Error::NotFound),
+            ApiError::NotFound
+        ));
+        assert!(matches!(
+            ApiError::from(anyhow::anyhow!("boom")),
+            ApiError::Internal(_)
+        ));
+    }
+
+    #[test]
+    fn field_errors_stay_per_field() {
+        let mut errors = FieldErrors::default();
+        errors.add("name", "Name is required");
+        let ApiError::Invalid(errors) = ApiError::from(errors) else {
+            panic!("field errors not kept");
+        };
+        assert_eq!(errors.to_string(), "Name is required");
+    }
+}
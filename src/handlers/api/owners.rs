@@ -0,0 +1,158 @@
+use super::{interact, ApiError, ApiJson, ApiPath, ApiUser};
+use crate::{
+    db::models::{
+        owner::{NewOwner, Owner},
+        pet::Pet,
+    },
+    errors::UserError,
+    logic::{
+        audit::{self, Actor, Entity},
+        permissions::Permission,
+        validation::OwnerInput,
+    },
+    Context,
+};
+use axum::{
+    extract::{Extension, Query},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use std::{collections::HashMap, sync::Arc};
+
+pub async fn list(
+    Extension(ctx): Extension<Arc<Context>>,
+    user: ApiUser,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<Vec<Owner>>, ApiError> {
+    user.require(Permission::View)?;
+
+    let owners = interact(&ctx, move |conn| match params.get("name") {
+        Some(n) => Owner::search_by_name(conn, n),
+        None => Ok(Owner::owners(conn)?),
+    })
+    .await?;
+
+    Ok(Json(owners))
+}
+
+pub async fn get(
+    Extension(ctx): Extension<Arc<Context>>,
+    user: ApiUser,
+    ApiPath(id): ApiPath<i32>,
+) -> Result<Json<Owner>, ApiError> {
+    user.require(Permission::View)?;
+
+    interact(&ctx, move |conn| Owner::select_by_id(conn, id))
+        .await?
+        .map(Json)
+        .ok_or(ApiError::NotFound)
+}
+
+pub async fn pets(
+    Extension(ctx): Extension<Arc<Context>>,
+    user: ApiUser,
+    ApiPath(id): ApiPath<i32>,
+) -> Result<Json<Vec<Pet>>, ApiError> {
+    user.require(Permission::View)?;
+
+    interact(&ctx, move |conn| {
+        Ok(match Owner::select_by_id(conn, id)? {
+            Some(_) => Some(Pet::select_by_owner(conn, id)?),
+            None => None,
+        })
+    })
+    .await?
+    .map(Json)
+    .ok_or(ApiError::NotFound)
+}
+
+pub async fn create(
+    Extension(ctx): Extension<Arc<Context>>,
+    user: ApiUser,
+    ApiJson(input): ApiJson<OwnerInput>,
+) -> Result<impl IntoResponse, ApiError> {
+    user.require(Permission::EditPets)?;
+    input.validate()?;
+
+    let owner = NewOwner {
+        name: input.name,
+        phone: input.phone,
+        email: input.email,
+        address: input.address,
+    };
+    let actor = Actor::from(&user.0);
+    let owner = interact(&ctx, move |conn| {
+        conn.immediate_transaction(|conn| {
+            let owner = owner.save(conn)?;
+            audit::created(conn, &actor, Entity::Owner, owner.id, &owner)?;
+            Ok(owner)
+        })
+    })
+    .await?;
+
+    Ok((StatusCode::CREATED, Json(owner)))
+}
+
+pub async fn update(
+    Extension(ctx): Extension<Arc<Context>>,
+    user: ApiUser,
+    ApiPath(id): ApiPath<i32>,
+    ApiJson(input): ApiJson<OwnerInput>,
+) -> Result<Json<Owner>, ApiError> {
+    user.require(Permission::EditPets)?;
+    input.validate()?;
+
+    let actor = Actor::from(&user.0);
+    let owner = interact(&ctx, move |conn| {
+        conn.immediate_transaction(|conn| {
+            let Some(before) = Owner::select_by_id(conn, id)? else {
+                return Ok(None);
+            };
+            let mut owner = before.clone();
+            owner.name = input.name;
+            owner.phone = input.phone;
+            owner.email = input.email;
+            owner.address = input.address;
+            owner.clone().update(conn)?;
+            audit::updated(conn, &actor, Entity::Owner, id, &before, &owner)?;
+            Ok(Some(owner))
+        })
+    })
+    .await?;
+
+    owner.map(Json).ok_or(ApiError::NotFound)
+}
+
+pub async fn delete(
+    Extension(ctx): Extension<Arc<Context>>,
+    user: ApiUser,
+    ApiPath(id): ApiPath<i32>,
+) -> Result<StatusCode, ApiError> {
+    user.require(Permission::DeletePets)?;
+
+    let actor = Actor::from(&user.0);
+    let deleted = interact(&ctx, move |conn| {
+        conn.immediate_transaction(|conn| {
+            if !Pet::select_by_owner(conn, id)?.is_empty() {
+                return Err(UserError::Conflict(format!(
+                    "Owner {id} still has pets, remove or reassign them first"
+                ))
+                .into());
+            }
+            let Some(owner) = Owner::select_by_id(conn, id)? else {
+                return Ok(false);
+            };
+            Owner::delete_by_id(conn, id)?;
+            audit::deleted(conn, &actor, Entity::Owner, id, &owner)?;
+            Ok(true)
+        })
+    })
+    .await?;
+
+    if deleted {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(ApiError::NotFound)
+    }
+}
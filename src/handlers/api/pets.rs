@@ -0,0 +1,122 @@
+use super::{interact, ApiError, ApiJson, ApiPath, ApiUser};
+use crate::{
+    db::models::pet::{NewPet, Pet},
+    logic::{
+        audit::{self, Actor, Entity},
+        deletion,
+        permissions::Permission,
+        validation::PetInput,
+    },
+    Context,
+};
+use axum::{
+    extract::{Extension, Query},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use chrono::Utc;
+use std::{collections::HashMap, sync::Arc};
+
+pub async fn list(
+    Extension(ctx): Extension<Arc<Context>>,
+    user: ApiUser,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<Vec<Pet>>, ApiError> {
+    user.require(Permission::View)?;
+
+    let pets = interact(&ctx, move |conn| match params.get("name") {
+        Some(n) => Pet::select_by_name(conn, n),
+        None => Ok(Pet::pets(conn)?),
+    })
+    .await?;
+
+    Ok(Json(pets))
+}
+
+pub async fn get(
+    Extension(ctx): Extension<Arc<Context>>,
+    user: ApiUser,
+    ApiPath(id): ApiPath<i32>,
+) -> Result<Json<Pet>, ApiError> {
+    user.require(Permission::View)?;
+
+    let pet = interact(&ctx, move |conn| Pet::select_by_id(conn, id)).await?;
+
+    Ok(Json(pet))
+}
+
+pub async fn create(
+    Extension(ctx): Extension<Arc<Context>>,
+    user: ApiUser,
+    ApiJson(input): ApiJson<PetInput>,
+) -> Result<impl IntoResponse, ApiError> {
+    user.require(Permission::EditPets)?;
+
+    let new_pet = NewPet {
+        name: input.name.clone(),
+        owner_id: input.owner_id,
+        age: input.age,
+        pet_type: input.pet_type,
+        vet_id: input.vet_id,
+        created_at: Utc::now().naive_utc(),
+        created_by: user.0.id,
+    };
+    let actor = Actor::from(&user.0);
+    let pet = interact(&ctx, move |conn| {
+        conn.immediate_transaction(|conn| {
+            input.validate(conn)?;
+            let pet = new_pet.save(conn)?;
+            audit::created(conn, &actor, Entity::Pet, pet.id, &pet)?;
+            Ok(pet)
+        })
+    })
+    .await?;
+
+    Ok((StatusCode::CREATED, Json(pet)))
+}
+
+pub async fn update(
+    Extension(ctx): Extension<Arc<Context>>,
+    user: ApiUser,
+    ApiPath(id): ApiPath<i32>,
+    ApiJson(input): ApiJson<PetInput>,
+) -> Result<Json<Pet>, ApiError> {
+    user.require(Permission::EditPets)?;
+
+    let actor = Actor::from(&user.0);
+    let pet = interact(&ctx, move |conn| {
+        conn.immediate_transaction(|conn| {
+            let before = Pet::select_by_id(conn, id)?;
+            input.validate(conn)?;
+            let mut pet = before.clone();
+            pet.name = input.name;
+            pet.owner_id = input.owner_id;
+            pet.age = input.age;
+            pet.pet_type = input.pet_type;
+            pet.vet_id = input.vet_id;
+            pet.clone().update(conn)?;
+            audit::updated(conn, &actor, Entity::Pet, id, &before, &pet)?;
+            Ok(pet)
+        })
+    })
+    .await?;
+
+    Ok(Json(pet))
+}
+
+pub async fn delete(
+    Extension(ctx): Extension<Arc<Context>>,
+    user: ApiUser,
+    ApiPath(id): ApiPath<i32>,
+) -> Result<StatusCode, ApiError> {
+    user.require(Permission::DeletePets)?;
+
+    let actor = Actor::from(&user.0);
+    interact(&ctx, move |conn| {
+        conn.immediate_transaction(|conn| deletion::delete_pet(conn, &actor, id))
+    })
+    .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
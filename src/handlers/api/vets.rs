@@ -0,0 +1,116 @@
+use super::{interact, ApiError, ApiJson, ApiPath, ApiUser};
+use crate::{
+    db::models::vet::{NewVet, Vet},
+    logic::{
+        audit::{self, Actor, Entity},
+        deletion,
+        permissions::Permission,
+        validation::VetInput,
+    },
+    Context,
+};
+use axum::{
+    extract::{Extension, Query},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use std::{collections::HashMap, sync::Arc};
+
+pub async fn list(
+    Extension(ctx): Extension<Arc<Context>>,
+    user: ApiUser,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<Vec<Vet>>, ApiError> {
+    user.require(Permission::View)?;
+
+    let vets = interact(&ctx, move |conn| match params.get("name") {
+        Some(n) => Vet::select_by_name(conn, n),
+        None => Ok(Vet::vets(conn)?),
+    })
+    .await?;
+
+    Ok(Json(vets))
+}
+
+pub async fn get(
+    Extension(ctx): Extension<Arc<Context>>,
+    user: ApiUser,
+    ApiPath(id): ApiPath<i32>,
+) -> Result<Json<Vet>, ApiError> {
+    user.require(Permission::View)?;
+
+    interact(&ctx, move |conn| Vet::select_by_id(conn, id))
+        .await?
+        .map(Json)
+        .ok_or(ApiError::NotFound)
+}
+
+pub async fn create(
+    Extension(ctx): Extension<Arc<Context>>,
+    user: ApiUser,
+    ApiJson(input): ApiJson<VetInput>,
+) -> Result<impl IntoResponse, ApiError> {
+    user.require(Permission::ManageVets)?;
+    input.validate()?;
+
+    let vet = NewVet { name: input.name };
+    let actor = Actor::from(&user.0);
+    let vet = interact(&ctx, move |conn| {
+        conn.immediate_transaction(|conn| {
+            let vet = vet.save(conn)?;
+            audit::created(conn, &actor, Entity::Vet, vet.id, &vet)?;
+            Ok(vet)
+        })
+    })
+    .await?;
+
+    Ok((StatusCode::CREATED, Json(vet)))
+}
+
+pub async fn update(
+    Extension(ctx): Extension<Arc<Context>>,
+    user: ApiUser,
+    ApiPath(id): ApiPath<i32>,
+    ApiJson(input): ApiJson<VetInput>,
+) -> Result<Json<Vet>, ApiError> {
+    user.require(Permission::ManageVets)?;
+    input.validate()?;
+
+    let actor = Actor::from(&user.0);
+    let vet = interact(&ctx, move |conn| {
+        conn.immediate_transaction(|conn| {
+            let Some(before) = Vet::select_by_id(conn, id)? else {
+                return Ok(None);
+            };
+            let mut vet = before.clone();
+            vet.name = input.name;
+            vet.clone().update(conn)?;
+            audit::updated(conn, &actor, Entity::Vet, id, &before, &vet)?;
+            Ok(Some(vet))
+        })
+    })
+    .await?;
+
+    vet.map(Json).ok_or(ApiError::NotFound)
+}
+
+pub async fn delete(
+    Extension(ctx): Extension<Arc<Context>>,
+    user: ApiUser,
+    ApiPath(id): ApiPath<i32>,
+) -> Result<StatusCode, ApiError> {
+    user.require(Permission::ManageVets)?;
+
+    let actor = Actor::from(&user.0);
+    let deleted = interact(&ctx, move |conn| {
+        conn.immediate_transaction(|conn| deletion::delete_vet(conn, &actor, id))
+    })
+    .await?;
+
+    if deleted {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(ApiError::NotFound)
+    }
+}
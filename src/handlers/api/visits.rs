@@ -0,0 +1,142 @@
+use super::{interact, ApiError, ApiJson, ApiPath, ApiUser};
+use crate::{
+    db::models::{
+        pet::Pet,
+        visit::{NewVisit, Visit},
+    },
+    logic::{
+        audit::{self, Actor, Entity},
+        permissions::Permission,
+        validation::VisitInput,
+    },
+    Context,
+};
+use axum::{extract::Extension, http::StatusCode, response::IntoResponse, Json};
+use std::sync::Arc;
+
+pub async fn list_by_pet(
+    Extension(ctx): Extension<Arc<Context>>,
+    user: ApiUser,
+    ApiPath(pet_id): ApiPath<i32>,
+) -> Result<Json<Vec<Visit>>, ApiError> {
+    user.require(Permission::View)?;
+
+    let visits = interact(&ctx, move |conn| {
+        // 404 for unknown pets rather than an empty list
+        Pet::select_by_id(conn, pet_id)?;
+        Visit::select_by_pet(conn, pet_id)
+    })
+    .await?;
+
+    Ok(Json(visits))
+}
+
+pub async fn list_by_vet(
+    Extension(ctx): Extension<Arc<Context>>,
+    user: ApiUser,
+    ApiPath(vet_id): ApiPath<i32>,
+) -> Result<Json<Vec<Visit>>, ApiError> {
+    user.require(Permission::View)?;
+
+    let visits = interact(&ctx, move |conn| Visit::select_by_vet(conn, vet_id)).await?;
+
+    Ok(Json(visits))
+}
+
+pub async fn get(
+    Extension(ctx): Extension<Arc<Context>>,
+    user: ApiUser,
+    ApiPath(id): ApiPath<i32>,
+) -> Result<Json<Visit>, ApiError> {
+    user.require(Permission::View)?;
+
+    interact(&ctx, move |conn| Visit::select_by_id(conn, id))
+        .await?
+        .map(Json)
+        .ok_or(ApiError::NotFound)
+}
+
+pub async fn create(
+    Extension(ctx): Extension<Arc<Context>>,
+    user: ApiUser,
+    ApiPath(pet_id): ApiPath<i32>,
+    ApiJson(input): ApiJson<VisitInput>,
+) -> Result<impl IntoResponse, ApiError> {
+    user.require(Permission::EditVisits)?;
+
+    let actor = Actor::from(&user.0);
+    let visit = interact(&ctx, move |conn| {
+        conn.immediate_transaction(|conn| {
+            Pet::select_by_id(conn, pet_id)?;
+            input.validate(conn)?;
+            let visit = NewVisit {
+                pet_id,
+                vet_id: input.vet_id,
+                visit_date: input.visit_date,
+                notes: input.notes,
+            }
+            .save(conn)?;
+            audit::created(conn, &actor, Entity::Visit, visit.id, &visit)?;
+            Ok(visit)
+        })
+    })
+    .await?;
+
+    Ok((StatusCode::CREATED, Json(visit)))
+}
+
+pub async fn update(
+    Extension(ctx): Extension<Arc<Context>>,
+    user: ApiUser,
+    ApiPath(id): ApiPath<i32>,
+    ApiJson(input): ApiJson<VisitInput>,
+) -> Result<Json<Visit>, ApiError> {
+    user.require(Permission::EditVisits)?;
+
+    let actor = Actor::from(&user.0);
+    let visit = interact(&ctx, move |conn| {
+        conn.immediate_transaction(|conn| {
+            let Some(before) = Visit::select_by_id(conn, id)? else {
+                return Ok(None);
+            };
+            input.validate(conn)?;
+            let mut visit = before.clone();
+            visit.vet_id = input.vet_id;
+            visit.visit_date = input.visit_date;
+            visit.notes = input.notes;
+            visit.clone().update(conn)?;
+            audit::updated(conn, &actor, Entity::Visit, id, &before, &visit)?;
+            Ok(Some(visit))
+        })
+    })
+    .await?;
+
+    visit.map(Json).ok_or(ApiError::NotFound)
+}
+
+pub async fn delete(
+    Extension(ctx): Extension<Arc<Context>>,
+    user: ApiUser,
+    ApiPath(id): ApiPath<i32>,
+) -> Result<StatusCode, ApiError> {
+    user.require(Permission::EditVisits)?;
+
+    let actor = Actor::from(&user.0);
+    let deleted = interact(&ctx, move |conn| {
+        conn.immediate_transaction(|conn| {
+            let Some(visit) = Visit::select_by_id(conn, id)? else {
+                return Ok(false);
+            };
+            Visit::delete_by_id(conn, id)?;
+            audit::deleted(conn, &actor, Entity::Visit, id, &visit)?;
+            Ok(true)
+        })
+    })
+    .await?;
+
+    if deleted {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(ApiError::NotFound)
+    }
+}
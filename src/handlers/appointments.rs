@@ -0,0 +1,279 @@
+use crate::{
+    db::models::{
+        appointment::{Appointment, AppointmentStatus, NewAppointment},
+        user::User,
+        vet::Vet,
+    },
+    errors::UserError,
+    logic::{
+        appointments::{self, pet_labels, SchedulingError},
+        audit::Actor,
+        validation::FieldErrors,
+    },
+    metrics, AppError, Context,
+};
+use axum::{
+    extract::{Extension, Path, Query},
+    http::StatusCode,
+    response::{Html, IntoResponse, Redirect, Response},
+    Form,
+};
+use chrono::{Days, Duration, NaiveDate, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tera::Tera;
+
+const DEFAULT_LENGTH_MINUTES: i64 = 30;
+
+#[derive(Deserialize)]
+pub struct CalendarParams {
+    vet_id: Option<i32>,
+    week: Option<NaiveDate>,
+}
+
+#[derive(Deserialize)]
+pub struct NewAppointmentParams {
+    vet_id: Option<i32>,
+    pet_id: Option<i32>,
+    date: Option<NaiveDate>,
+}
+
+#[derive(Deserialize)]
+pub struct AppointmentForm {
+    pub id: i32,
+    pub pet_id: i32,
+    pub vet_id: i32,
+    pub date: NaiveDate,
+    pub starts: NaiveTime,
+    pub ends: NaiveTime,
+    pub status: AppointmentStatus,
+    pub reason: String,
+}
+
+impl AppointmentForm {
+    fn reason(&self) -> Option<String> {
+        let reason = self.reason.trim();
+        if reason.is_empty() {
+            None
+        } else {
+            Some(reason.to_string())
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct CalendarDay {
+    date: NaiveDate,
+    appointments: Vec<Appointment>,
+}
+
+/// Week view of one vet's appointments.
+pub async fn calendar(
+    Extension(tera): Extension<Tera>,
+    Extension(ctx): Extension<Arc<Context>>,
+    Query(params): Query<CalendarParams>,
+) -> Result<Html<String>, AppError> {
+    let db_conn = ctx.db_connection().await?;
+    let mut c = tera::Context::new();
+
+    let week = appointments::week_start(params.week.unwrap_or_else(|| Utc::now().date_naive()));
+
+    let (vets, vet_id, week_appointments, pets) = db_conn
+        .interact(move |conn| -> anyhow::Result<_> {
+            let vets = Vet::vets(conn)?;
+            let vet_id = params
+                .vet_id
+                .or_else(|| vets.first().map(|v| v.id))
+                .unwrap_or_default();
+            let from = week.and_time(NaiveTime::MIN);
+            let to = (week + Days::new(7)).and_time(NaiveTime::MIN);
+            let week_appointments = Appointment::select_by_vet_between(conn, vet_id, from, to)?;
+            Ok((vets, vet_id, week_appointments, pet_labels(conn)?))
+        })
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    let mut days: Vec<CalendarDay> = (0..7)
+        .map(|offset| CalendarDay {
+            date: week + Days::new(offset),
+            appointments: Vec::new(),
+        })
+        .collect();
+    for appointment in week_appointments {
+        let offset = (appointment.starts_at.date() - week).num_days() as usize;
+        if let Some(day) = days.get_mut(offset) {
+            day.appointments.push(appointment);
+        }
+    }
+
+    c.insert("vets", &vets);
+    c.insert("vet_id", &vet_id);
+    c.insert("week", &week);
+    c.insert("previous_week", &(week - Days::new(7)));
+    c.insert("next_week", &(week + Days::new(7)));
+    c.insert("days", &days);
+    c.insert("pet_labels", &pets);
+
+    let r = metrics::render(&tera, "appointment/calendar.html", &c)?;
+
+    Ok(Html::from(r))
+}
+
+/// The edit page of `appointment`, showing why a rejected form wasn't saved.
+async fn render_edit(
+    tera: &Tera,
+    ctx: &Context,
+    appointment: Appointment,
+    error: Option<String>,
+    errors: &FieldErrors,
+) -> Result<Html<String>, AppError> {
+    let db_conn = ctx.db_connection().await?;
+    let mut c = tera::Context::new();
+
+    let (vets, pets) = db_conn
+        .interact(|conn| -> anyhow::Result<_> { Ok((Vet::vets(conn)?, pet_labels(conn)?)) })
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    let mut pets: Vec<(i32, String)> = pets.into_iter().collect();
+    pets.sort_by(|a, b| a.1.cmp(&b.1));
+
+    c.insert("appointment", &appointment);
+    c.insert("date", &appointment.starts_at.date());
+    c.insert("starts", &appointment.starts_at.format("%H:%M").to_string());
+    c.insert("ends", &appointment.ends_at.format("%H:%M").to_string());
+    c.insert("statuses", &AppointmentStatus::ALL);
+    c.insert("vets", &vets);
+    c.insert("pets", &pets);
+    if let Some(error) = error {
+        c.insert("error", &error);
+    }
+    c.insert("errors", errors);
+
+    let r = metrics::render(tera, "appointment/edit.html", &c)?;
+
+    Ok(Html::from(r))
+}
+
+pub async fn get(
+    Extension(tera): Extension<Tera>,
+    Extension(ctx): Extension<Arc<Context>>,
+    Path(id): Path<i32>,
+    Query(params): Query<NewAppointmentParams>,
+) -> Result<Response, AppError> {
+    let db_conn = ctx.db_connection().await?;
+
+    let appointment = db_conn
+        .interact(move |conn| Appointment::select_by_id(conn, id))
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    let appointment = match appointment {
+        Some(appointment) => appointment,
+        None if id == 0 => {
+            let starts_at = params
+                .date
+                .unwrap_or_else(|| Utc::now().date_naive())
+                .and_hms_opt(9, 0, 0)
+                .unwrap_or_default();
+            Appointment {
+                pet_id: params.pet_id.unwrap_or_default(),
+                vet_id: params.vet_id.unwrap_or_default(),
+                starts_at,
+                ends_at: starts_at + Duration::minutes(DEFAULT_LENGTH_MINUTES),
+                ..Appointment::default()
+            }
+        }
+        None => return Err(AppError::NotFound),
+    };
+
+    Ok(
+        render_edit(&tera, &ctx, appointment, None, &FieldErrors::default())
+            .await?
+            .into_response(),
+    )
+}
+
+pub async fn save(
+    Extension(tera): Extension<Tera>,
+    Extension(ctx): Extension<Arc<Context>>,
+    user: User,
+    Form(form): Form<AppointmentForm>,
+) -> Result<Response, AppError> {
+    let db_conn = ctx.db_connection().await?;
+
+    let starts_at = form.date.and_time(form.starts);
+    let ends_at = form.date.and_time(form.ends);
+    let reason = form.reason();
+    let submitted = Appointment {
+        id: form.id,
+        pet_id: form.pet_id,
+        vet_id: form.vet_id,
+        starts_at,
+        ends_at,
+        status: form.status,
+        reason,
+        created_at: Utc::now().naive_utc(),
+        created_by: user.id,
+        sequence: 0,
+    };
+
+    let to_save = submitted.clone();
+    let actor = Actor::from(&user);
+    let result = db_conn
+        .interact(move |conn| -> anyhow::Result<()> {
+            if to_save.id == 0 {
+                return appointments::book(
+                    conn,
+                    &actor,
+                    NewAppointment {
+                        pet_id: to_save.pet_id,
+                        vet_id: to_save.vet_id,
+                        starts_at: to_save.starts_at,
+                        ends_at: to_save.ends_at,
+                        status: to_save.status,
+                        reason: to_save.reason,
+                        created_at: to_save.created_at,
+                        created_by: to_save.created_by,
+                    },
+                )
+                .map(|_| ());
+            }
+            let Some(existing) = Appointment::select_by_id(conn, to_save.id)? else {
+                return Err(UserError::NotFound.into());
+            };
+            appointments::reschedule(
+                conn,
+                &actor,
+                Appointment {
+                    created_at: existing.created_at,
+                    created_by: existing.created_by,
+                    sequence: existing.sequence,
+                    ..to_save
+                },
+            )
+        })
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))?;
+
+    let e = match result {
+        Ok(()) => {
+            return Ok(Redirect::to(&format!(
+                "/appointments?vet_id={}&week={}",
+                submitted.vet_id,
+                appointments::week_start(submitted.starts_at.date())
+            ))
+            .into_response())
+        }
+        Err(e) => e,
+    };
+    let (error, errors) = match e.downcast::<SchedulingError>() {
+        Ok(scheduling_error) => (Some(scheduling_error.to_string()), FieldErrors::default()),
+        Err(e) => (None, e.downcast::<FieldErrors>()?),
+    };
+    Ok((
+        StatusCode::UNPROCESSABLE_ENTITY,
+        render_edit(&tera, &ctx, submitted, error, &errors).await?,
+    )
+        .into_response())
+}
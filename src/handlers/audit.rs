@@ -0,0 +1,102 @@
+use crate::{
+    db::models::audit_log::{AuditLog, AuditLogFilter},
+    logic::audit::{Action, Entity},
+    metrics, AppError, Context,
+};
+use axum::{
+    extract::{Extension, Query},
+    response::Html,
+};
+use chrono::{Days, NaiveDate};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::sync::Arc;
+use tera::Tera;
+
+const PAGE_SIZE: i64 = 50;
+
+/// Filters of the audit log page. Empty form fields arrive as empty strings
+/// and mean "any".
+#[derive(Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct AuditParams {
+    entity_type: String,
+    entity_id: String,
+    actor: String,
+    action: String,
+    from: String,
+    until: String,
+    page: Option<i64>,
+}
+
+impl AuditParams {
+    fn filter(&self) -> AuditLogFilter {
+        fn given(value: &str) -> Option<&str> {
+            Some(value.trim()).filter(|value| !value.is_empty())
+        }
+        let date = |value: &str| given(value)?.parse::<NaiveDate>().ok();
+
+        AuditLogFilter {
+            entity_type: given(&self.entity_type)
+                .and_then(|value| value.parse::<Entity>().ok())
+                .map(|entity| entity.as_str().to_string()),
+            entity_id: given(&self.entity_id).and_then(|value| value.parse().ok()),
+            actor_name: given(&self.actor).map(str::to_string),
+            action: given(&self.action)
+                .and_then(|value| value.parse::<Action>().ok())
+                .map(|action| action.as_str().to_string()),
+            from: date(&self.from).map(|day| day.and_hms_opt(0, 0, 0).unwrap_or_default()),
+            // The until day is included
+            until: date(&self.until)
+                .and_then(|day| day.checked_add_days(Days::new(1)))
+                .map(|day| day.and_hms_opt(0, 0, 0).unwrap_or_default()),
+        }
+    }
+}
+
+/// An entry with its changes decoded for the template.
+#[derive(Serialize)]
+struct Entry {
+    entry: AuditLog,
+    changes: Map<String, Value>,
+}
+
+pub async fn list(
+    Extension(tera): Extension<Tera>,
+    Extension(ctx): Extension<Arc<Context>>,
+    Query(params): Query<AuditParams>,
+) -> Result<Html<String>, AppError> {
+    let db_conn = ctx.db_connection().await?;
+    let mut c = tera::Context::new();
+
+    let page = params.page.unwrap_or(1).max(1);
+    let offset = (page - 1)
+        .checked_mul(PAGE_SIZE)
+        .ok_or_else(|| AppError::Validation(format!("Page {page} does not exist")))?;
+    let filter = params.filter();
+    // One more than shown tells whether there is a next page
+    let mut entries = db_conn
+        .interact(move |conn| AuditLog::search(conn, &filter, PAGE_SIZE + 1, offset))
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+    let has_next = entries.len() as i64 > PAGE_SIZE;
+    entries.truncate(PAGE_SIZE as usize);
+
+    let entries: Vec<Entry> = entries
+        .into_iter()
+        .map(|entry| Entry {
+            changes: serde_json::from_str(&entry.changes).unwrap_or_default(),
+            entry,
+        })
+        .collect();
+
+    c.insert("entries", &entries);
+    c.insert("params", &params);
+    c.insert("entity_types", &Entity::ALL.map(|entity| entity.as_str()));
+    c.insert("actions", &Action::ALL.map(|action| action.as_str()));
+    c.insert("page", &page);
+    c.insert("has_next", &has_next);
+    let r = metrics::render(&tera, "audit/list.html", &c)?;
+
+    Ok(Html::from(r))
+}
@@ -1,17 +1,26 @@
 use crate::{
-    logic::users::{self},
+    csrf,
+    db::models::user::User,
+    logic::{
+        lockout::{self, LoginBlock},
+        sessions, two_factor,
+        users::{self},
+    },
+    metrics::{self, metrics},
+    server::ClientAddr,
+    settings::Settings,
     AppError, Context,
 };
 use axum::{
-    extract::{Extension, Form, Query},
+    extract::{ConnectInfo, Extension, Form, Query},
+    http::{header, HeaderMap},
     response::{Html, IntoResponse, Redirect},
 };
-use axum_extra::extract::cookie::{Cookie, CookieJar};
-use redis::{Commands, RedisError};
+use axum_extra::extract::cookie::{Cookie, CookieJar, SameSite};
 use serde::Deserialize;
 use std::sync::Arc;
 use tera::Tera;
-use tracing::info;
+use tracing::{info, warn};
 
 #[derive(Deserialize, Debug)]
 pub struct LoginForm {
@@ -19,14 +28,53 @@ pub struct LoginForm {
     password: String,
 }
 
+#[derive(Deserialize, Debug)]
+pub struct TwoFactorForm {
+    code: String,
+}
+
 #[derive(Deserialize, Debug)]
 pub struct LoginParams {
     error: Option<String>,
+    locked: Option<String>,
+    retry_after: Option<u64>,
 }
 
-pub async fn logout(jar: CookieJar) -> Result<impl IntoResponse, AppError> {
-    let session_cookie = Cookie::new("axum_session", "");
-    let updated_jar = jar.remove(session_cookie);
+pub const SESSION_COOKIE: &str = "axum_session";
+/// Names the login waiting for its second factor, see [`post_two_factor`].
+const PENDING_LOGIN_COOKIE: &str = "axum_pending_login";
+
+/// Session cookie, hidden from scripts and not sent along cross-site requests
+/// other than top-level navigation.
+fn session_cookie(settings: &Settings, key: String) -> Cookie<'static> {
+    Cookie::build((SESSION_COOKIE, key))
+        .path("/")
+        .http_only(true)
+        .same_site(SameSite::Lax)
+        .secure(settings.session.secure_cookie)
+        .build()
+}
+
+fn pending_login_cookie(settings: &Settings, key: String) -> Cookie<'static> {
+    Cookie::build((PENDING_LOGIN_COOKIE, key))
+        .path("/login")
+        .http_only(true)
+        .same_site(SameSite::Strict)
+        .secure(settings.session.secure_cookie)
+        .build()
+}
+
+pub async fn logout(
+    Extension(ctx): Extension<Arc<Context>>,
+    jar: CookieJar,
+) -> Result<impl IntoResponse, AppError> {
+    if let Some(cookie) = jar.get(SESSION_COOKIE) {
+        ctx.sessions.remove(cookie.value()).await?;
+    }
+
+    let updated_jar = jar
+        .remove(Cookie::build(SESSION_COOKIE).path("/"))
+        .add(csrf::cookie(&ctx.settings, csrf::new_token()));
 
     Ok((updated_jar, Redirect::to("/")))
 }
@@ -37,38 +85,201 @@ pub async fn login(
 ) -> Result<Html<String>, AppError> {
     let mut c = tera::Context::new();
 
-    if let Some(_error) = &params.error {
+    if params.locked.is_some() {
+        c.insert(
+            "error",
+            "Too many failed logins, this account is temporarily locked. Try again later or ask an administrator to unlock it.",
+        );
+    } else if let Some(seconds) = params.retry_after {
+        c.insert(
+            "error",
+            &format!("Too many failed logins, wait {seconds} seconds before trying again"),
+        );
+    } else if let Some(_error) = &params.error {
         c.insert("error", "Invalid credentials (try with admin/admin)");
     }
-    let r = tera.render("auth/login.html", &c)?;
+    let r = metrics::render(&tera, "auth/login.html", &c)?;
 
     Ok(Html::from(r))
 }
 
 pub async fn post_login(
     Extension(ctx): Extension<Arc<Context>>,
+    ConnectInfo(addr): ConnectInfo<ClientAddr>,
+    headers: HeaderMap,
     jar: CookieJar,
     Form(login): Form<LoginForm>,
 ) -> Result<impl IntoResponse, AppError> {
-    let session_cookie = Cookie::new("axum_session", users::session_key());
+    let sessions = ctx.sessions.as_ref();
+
+    match lockout::check(sessions, &login.username, addr.0).await? {
+        Some(LoginBlock::Locked(_)) => {
+            metrics().login("blocked");
+            warn!(
+                "Login attempt for locked user {} from {addr}",
+                login.username
+            );
+            return Ok((jar, Redirect::to("/login?locked")));
+        }
+        Some(LoginBlock::Backoff(wait)) => {
+            metrics().login("blocked");
+            let seconds = wait.as_secs_f64().ceil() as u64;
+            return Ok((jar, Redirect::to(&format!("/login?retry_after={seconds}"))));
+        }
+        None => {}
+    }
 
     let user = users::authenticate(&login.username, &login.password, &ctx).await?;
 
-    if let Some(user) = user {
-        // Add a session to redis, where the key is the cookie value
-        // And the redis value is the user info
-        let mut conn = ctx.redis_connection.lock().await;
-
-        let redis_key = session_cookie.name_value().1;
-        let redis_value = serde_json::to_string(&user)?;
-        info!("Redis key {} Value: {}", &redis_key, &redis_value);
-        let redis_response: Result<(), RedisError> = conn.set_ex(redis_key, redis_value, 10);
-        if let Err(e) = redis_response {
-            tracing::error!("Cannot write into redis: {:?}", e);
+    let Some(user) = user else {
+        metrics().login("failure");
+        let failures = lockout::record_failure(sessions, &login.username, addr.0).await?;
+        warn!(
+            "Failed login for {} from {addr} ({failures} recent failures)",
+            login.username
+        );
+        return Ok((jar, Redirect::to("/login?error")));
+    };
+
+    let db_conn = ctx.db_connection().await?;
+    let user_id = user.id;
+    let two_factor = db_conn
+        .interact(move |conn| two_factor::is_enabled(conn, user_id))
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    if two_factor {
+        // No session until the second factor is entered, failures stay counted
+        let key = users::session_key();
+        sessions
+            .save_pending_login(&key, user.id, two_factor::PENDING_LOGIN_TTL)
+            .await?;
+        let updated_jar = jar.add(pending_login_cookie(&ctx.settings, key));
+        return Ok((updated_jar, Redirect::to("/login/two-factor")));
+    }
+
+    lockout::record_success(sessions, &login.username, addr.0).await?;
+    metrics().login("success");
+    Ok(start_session(&ctx, jar, &user, addr, &headers).await)
+}
+
+pub async fn two_factor(
+    Extension(tera): Extension<Tera>,
+    jar: CookieJar,
+    params: Query<LoginParams>,
+) -> Result<impl IntoResponse, AppError> {
+    if jar.get(PENDING_LOGIN_COOKIE).is_none() {
+        return Ok(Redirect::to("/login").into_response());
+    }
+
+    let mut c = tera::Context::new();
+    if let Some(seconds) = params.retry_after {
+        c.insert(
+            "error",
+            &format!("Too many failed logins, wait {seconds} seconds before trying again"),
+        );
+    } else if params.error.is_some() {
+        c.insert("error", "Invalid code");
+    }
+    let r = metrics::render(&tera, "auth/two_factor.html", &c)?;
+
+    Ok(Html::from(r).into_response())
+}
+
+/// Second login step: checks the TOTP or recovery code of the user whose
+/// password was accepted by [`post_login`] and only then starts the session.
+pub async fn post_two_factor(
+    Extension(ctx): Extension<Arc<Context>>,
+    ConnectInfo(addr): ConnectInfo<ClientAddr>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Form(form): Form<TwoFactorForm>,
+) -> Result<impl IntoResponse, AppError> {
+    let sessions = ctx.sessions.as_ref();
+
+    let Some(key) = jar.get(PENDING_LOGIN_COOKIE).map(|c| c.value().to_string()) else {
+        return Ok((jar, Redirect::to("/login")));
+    };
+    // Used once the login succeeds or cannot continue
+    let cleared_jar = jar
+        .clone()
+        .remove(Cookie::build(PENDING_LOGIN_COOKIE).path("/login"));
+    let Some(user_id) = sessions.pending_login(&key).await? else {
+        return Ok((cleared_jar, Redirect::to("/login")));
+    };
+
+    let db_conn = ctx.db_connection().await?;
+    let user = db_conn
+        .interact(move |conn| User::select_by_id(conn, user_id))
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+    let Some(user) = user.filter(|user| !user.disabled) else {
+        sessions.remove_pending_login(&key).await?;
+        return Ok((cleared_jar, Redirect::to("/login?error")));
+    };
+
+    match lockout::check(sessions, &user.username, addr.0).await? {
+        Some(LoginBlock::Locked(_)) => {
+            metrics().login("blocked");
+            warn!(
+                "Two-factor attempt for locked user {} from {addr}",
+                user.username
+            );
+            sessions.remove_pending_login(&key).await?;
+            return Ok((cleared_jar, Redirect::to("/login?locked")));
+        }
+        Some(LoginBlock::Backoff(wait)) => {
+            metrics().login("blocked");
+            let seconds = wait.as_secs_f64().ceil() as u64;
+            let redirect = Redirect::to(&format!("/login/two-factor?retry_after={seconds}"));
+            return Ok((jar, redirect));
         }
-        let updated_jar = jar.add(session_cookie);
-        Ok((updated_jar, Redirect::to("/pets")))
-    } else {
-        Ok((jar, Redirect::to("/login?error")))
+        None => {}
+    }
+
+    let valid = db_conn
+        .interact(move |conn| two_factor::verify(conn, user_id, &form.code))
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    if !valid {
+        metrics().login("failure");
+        let failures = lockout::record_failure(sessions, &user.username, addr.0).await?;
+        warn!(
+            "Wrong two-factor code for {} from {addr} ({failures} recent failures)",
+            user.username
+        );
+        return Ok((jar, Redirect::to("/login/two-factor?error")));
+    }
+
+    sessions.remove_pending_login(&key).await?;
+    lockout::record_success(sessions, &user.username, addr.0).await?;
+    metrics().login("success");
+    Ok(start_session(&ctx, cleared_jar, &user, addr, &headers).await)
+}
+
+async fn start_session(
+    ctx: &Context,
+    jar: CookieJar,
+    user: &User,
+    addr: ClientAddr,
+    headers: &HeaderMap,
+) -> (CookieJar, Redirect) {
+    let session_cookie = session_cookie(&ctx.settings, users::session_key());
+
+    // The session key is the cookie value, the stored value is the user info
+    let session_key = session_cookie.name_value().1;
+    info!("New session for {}", &user.username);
+    let user_agent = headers
+        .get(header::USER_AGENT)
+        .and_then(|value| value.to_str().ok());
+    let info = sessions::new_info(user.id, addr.0, user_agent);
+    if let Err(e) = ctx.sessions.save(session_key, user, &info).await {
+        tracing::error!("Cannot store session: {:?}", e);
     }
+    // A new session gets a new CSRF token as well
+    let updated_jar = jar
+        .add(session_cookie)
+        .add(csrf::cookie(&ctx.settings, csrf::new_token()));
+    (updated_jar, Redirect::to("/pets"))
 }
@@ -0,0 +1,99 @@
+use crate::{
+    db::models::{user::User, vet::Vet},
+    logic::{
+        audit::Actor,
+        calendar::{create_feed_token, verify_feed_token, vet_calendar},
+    },
+    metrics, AppError, Context,
+};
+use axum::{
+    extract::{Extension, Path, Query},
+    http::header,
+    response::{Html, IntoResponse, Response},
+};
+use serde::Deserialize;
+use std::sync::Arc;
+use tera::Tera;
+
+#[derive(Deserialize)]
+pub struct FeedParams {
+    token: String,
+}
+
+/// `.ics` feed of one vet, authorized by the feed token in the query string.
+/// Unknown vets and wrong tokens both answer 404 so feed URLs can't be probed.
+pub async fn feed(
+    Extension(ctx): Extension<Arc<Context>>,
+    Path(id): Path<i32>,
+    Query(params): Query<FeedParams>,
+) -> Result<Response, AppError> {
+    let db_conn = ctx.db_connection().await?;
+
+    let ics = db_conn
+        .interact(move |conn| -> anyhow::Result<_> {
+            if !verify_feed_token(conn, id, &params.token)? {
+                return Ok(None);
+            }
+            vet_calendar(conn, id)
+        })
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    Ok(match ics {
+        Some(ics) => (
+            [
+                (
+                    header::CONTENT_TYPE,
+                    "text/calendar; charset=utf-8".to_string(),
+                ),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("inline; filename=\"vet-{id}.ics\""),
+                ),
+            ],
+            ics,
+        )
+            .into_response(),
+        None => AppError::NotFound.into_response(),
+    })
+}
+
+/// Issues a new feed URL for a vet and shows it once on the vet page.
+pub async fn new_token(
+    Extension(tera): Extension<Tera>,
+    Extension(ctx): Extension<Arc<Context>>,
+    user: User,
+    Path(id): Path<i32>,
+) -> Result<Response, AppError> {
+    let db_conn = ctx.db_connection().await?;
+    let mut c = tera::Context::new();
+
+    let actor = Actor::from(&user);
+    let vet = db_conn
+        .interact(move |conn| -> anyhow::Result<_> {
+            let Some(vet) = Vet::select_by_id(conn, id)? else {
+                return Ok(None);
+            };
+            let token = create_feed_token(conn, &actor, id)?;
+            Ok(Some((vet, token)))
+        })
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    let (vet, token) = vet.ok_or(AppError::NotFound)?;
+
+    c.insert("vet", &vet);
+    c.insert("has_feed", &true);
+    c.insert("feed_url", &feed_url(&ctx, id, &token));
+
+    let r = metrics::render(&tera, "vet/edit.html", &c)?;
+
+    Ok(Html::from(r).into_response())
+}
+
+/// Absolute feed URL under the configured `public_url`, never taken from
+/// request headers a client could forge to send the token elsewhere.
+fn feed_url(ctx: &Context, vet_id: i32, token: &str) -> String {
+    let base_url = ctx.settings.base_url();
+    format!("{base_url}/vets/{vet_id}/calendar.ics?token={token}")
+}
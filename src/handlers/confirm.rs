@@ -0,0 +1,23 @@
+use crate::{metrics, AppError};
+use axum::response::Html;
+use serde::Serialize;
+use tera::Tera;
+
+/// Page asking to confirm a destructive action before it is POSTed to `action`.
+#[derive(Serialize)]
+pub struct Confirmation {
+    pub title: String,
+    pub message: String,
+    pub action: String,
+    /// Where "Cancel" leads back to.
+    pub cancel: String,
+}
+
+impl Confirmation {
+    pub fn render(&self, tera: &Tera) -> Result<Html<String>, AppError> {
+        let c = tera::Context::from_serialize(self)?;
+        let r = metrics::render(tera, "confirm.html", &c)?;
+
+        Ok(Html::from(r))
+    }
+}
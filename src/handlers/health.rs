@@ -0,0 +1,178 @@
+use crate::{metrics::metrics, Context};
+use axum::{
+    extract::Extension,
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use diesel::{sql_query, RunQueryDsl};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::{
+    collections::BTreeMap,
+    future::Future,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use subtle::ConstantTimeEq;
+use tera::Tera;
+
+const CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[derive(Serialize)]
+pub struct Check {
+    status: &'static str,
+    latency_ms: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct Readiness {
+    status: &'static str,
+    checks: BTreeMap<&'static str, Check>,
+}
+
+/// Runs a single dependency probe, bounded by [`CHECK_TIMEOUT`].
+async fn check<F>(probe: F) -> Check
+where
+    F: Future<Output = anyhow::Result<()>>,
+{
+    let start = Instant::now();
+    let result = match tokio::time::timeout(CHECK_TIMEOUT, probe).await {
+        Ok(result) => result,
+        Err(_) => Err(anyhow::anyhow!("timed out after {CHECK_TIMEOUT:?}")),
+    };
+    let latency_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+    match result {
+        Ok(()) => Check {
+            status: "ok",
+            latency_ms,
+            error: None,
+        },
+        Err(e) => {
+            tracing::warn!("Readiness check failed: {e:?}");
+            Check {
+                status: "error",
+                latency_ms,
+                error: Some(e.to_string()),
+            }
+        }
+    }
+}
+
+async fn probe_database(ctx: &Context) -> anyhow::Result<()> {
+    let db_conn = ctx.db_connection().await?;
+    db_conn
+        .interact(|conn| sql_query("SELECT 1").execute(conn))
+        .await
+        .map_err(|e| anyhow::Error::msg(e.to_string()))??;
+    Ok(())
+}
+
+async fn probe_sessions(ctx: &Context) -> anyhow::Result<()> {
+    ctx.sessions.ping().await
+}
+
+async fn probe_templates(tera: &Tera) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        tera.get_template_names().next().is_some(),
+        "no templates loaded"
+    );
+    Ok(())
+}
+
+/// Liveness: the process is up and serving requests.
+pub async fn healthz() -> Json<Value> {
+    Json(json!({ "status": "ok" }))
+}
+
+/// Readiness: every dependency needed to serve pages answers and the server
+/// isn't shutting down.
+pub async fn readyz(
+    Extension(ctx): Extension<Arc<Context>>,
+    Extension(tera): Extension<Tera>,
+) -> (StatusCode, Json<Readiness>) {
+    if ctx.is_draining() {
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(Readiness {
+                status: "shutting_down",
+                checks: BTreeMap::new(),
+            }),
+        );
+    }
+
+    let checks = BTreeMap::from([
+        ("database", check(probe_database(&ctx)).await),
+        ("sessions", check(probe_sessions(&ctx)).await),
+        ("templates", check(probe_templates(&tera)).await),
+    ]);
+
+    let ready = checks.values().all(|c| c.error.is_none());
+    let status = if ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+
+    (
+        status,
+        Json(Readiness {
+            status: if ready { "ok" } else { "error" },
+            checks,
+        }),
+    )
+}
+
+pub async fn version(Extension(ctx): Extension<Arc<Context>>) -> Json<Value> {
+    Json(json!({
+        "name": env!("CARGO_PKG_NAME"),
+        "version": env!("CARGO_PKG_VERSION"),
+        "git_hash": env!("PETCLINIC_GIT_HASH"),
+        "config_name": ctx.settings.config_name,
+    }))
+}
+
+/// Prometheus scrape endpoint. The scraper has to send `metrics.token` as a
+/// bearer token, without one configured the metrics aren't served at all.
+pub async fn metrics_text(Extension(ctx): Extension<Arc<Context>>, headers: HeaderMap) -> Response {
+    let Some(token) = &ctx.settings.metrics.token else {
+        tracing::debug!("Refused to serve /metrics, metrics.token is not set");
+        return StatusCode::FORBIDDEN.into_response();
+    };
+    let authorized = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|sent| bool::from(sent.as_bytes().ct_eq(token.as_bytes())));
+    if !authorized {
+        return (
+            StatusCode::UNAUTHORIZED,
+            [(header::WWW_AUTHENTICATE, "Bearer")],
+        )
+            .into_response();
+    }
+
+    let metrics = metrics();
+    let pool = ctx.db_connection_pool.status();
+    metrics
+        .db_pool_connections
+        .with_label_values(&["in_use"])
+        .set(pool.size.saturating_sub(pool.available) as i64);
+    metrics
+        .db_pool_connections
+        .with_label_values(&["idle"])
+        .set(pool.available as i64);
+    metrics.db_pool_max_connections.set(pool.max_size as i64);
+    metrics.db_pool_waiting.set(pool.waiting as i64);
+
+    match metrics.encode() {
+        Ok(text) => ([(header::CONTENT_TYPE, "text/plain; version=0.0.4")], text).into_response(),
+        Err(e) => {
+            tracing::error!("Cannot encode metrics: {e:?}");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::AppError;
+use crate::{metrics, AppError};
 use axum::{extract::Extension, response::Html};
 use tera::Tera;
 
@@ -7,7 +7,7 @@ pub async fn home(Extension(tera): Extension<Tera>) -> Result<Html<String>, AppE
 
     tracing::debug!("Main request");
 
-    let r = tera.render("home.html", &c)?;
+    let r = metrics::render(&tera, "home.html", &c)?;
 
     Ok(Html::from(r))
 }
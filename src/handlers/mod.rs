@@ -1,4 +1,15 @@
+pub mod api;
+pub mod appointments;
+pub mod audit;
 pub mod auth;
+pub mod calendar;
+pub mod confirm;
+pub mod health;
 pub mod home;
+pub mod owners;
 pub mod pets;
+pub mod sessions;
+pub mod two_factor;
+pub mod users;
 pub mod vets;
+pub mod visits;
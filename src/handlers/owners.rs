@@ -0,0 +1,250 @@
+use crate::{
+    db::models::{
+        owner::{NewOwner, Owner},
+        pet::{self, Pet},
+        user::User,
+    },
+    errors::UserError,
+    handlers::confirm::Confirmation,
+    logic::{
+        audit::{self, Actor, Entity},
+        validation::{FieldErrors, OwnerInput},
+    },
+    metrics, AppError, Context,
+};
+use axum::{
+    extract::{Extension, Path, Query},
+    http::StatusCode,
+    response::{Html, IntoResponse, Redirect, Response},
+    Form,
+};
+use serde::Deserialize;
+use std::{collections::HashMap, sync::Arc};
+use tera::Tera;
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+pub struct OwnerForm {
+    pub id: String,
+    pub name: String,
+    pub phone: String,
+    pub email: String,
+    pub address: String,
+}
+
+/// Empty optional form fields are stored as NULL.
+fn optional(value: &str) -> Option<String> {
+    let value = value.trim();
+    if value.is_empty() {
+        None
+    } else {
+        Some(value.to_string())
+    }
+}
+
+pub async fn list(
+    Extension(tera): Extension<Tera>,
+    Extension(ctx): Extension<Arc<Context>>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Html<String>, AppError> {
+    let db_conn = ctx.db_connection().await?;
+    let mut c = tera::Context::new();
+
+    let owners = db_conn
+        .interact(move |conn| {
+            let name = params.get("name");
+            match name {
+                Some(n) => Owner::search_by_name(conn, n),
+                None => Ok(Owner::owners(conn)?),
+            }
+        })
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    let pets = db_conn
+        .interact(Pet::pets)
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    let mut pet_names: HashMap<i32, Vec<String>> = HashMap::new();
+    for pet in pets {
+        pet_names.entry(pet.owner_id).or_default().push(pet.name);
+    }
+
+    c.insert("owners", &owners);
+    c.insert("pet_names", &pet_names);
+
+    let r = metrics::render(&tera, "owner/list.html", &c)?;
+
+    Ok(Html::from(r))
+}
+
+pub async fn get(
+    Extension(tera): Extension<Tera>,
+    Extension(ctx): Extension<Arc<Context>>,
+    Path(id): Path<i32>,
+) -> Result<Response, AppError> {
+    let db_conn = ctx.db_connection().await?;
+    let owner = db_conn
+        .interact(move |conn| Owner::select_by_id(conn, id))
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    let owner = match owner {
+        Some(owner) => owner,
+        None if id == 0 => Owner::default(),
+        None => return Err(AppError::NotFound),
+    };
+
+    Ok(render_edit(&tera, &ctx, owner, &FieldErrors::default())
+        .await?
+        .into_response())
+}
+
+/// The edit page of `owner`, showing what was wrong with a rejected form.
+async fn render_edit(
+    tera: &Tera,
+    ctx: &Context,
+    owner: Owner,
+    errors: &FieldErrors,
+) -> Result<Html<String>, AppError> {
+    let db_conn = ctx.db_connection().await?;
+    let mut c = tera::Context::new();
+
+    let id = owner.id;
+    let pets = db_conn
+        .interact(move |conn| Pet::select_by_owner(conn, id))
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    c.insert("owner", &owner);
+    c.insert("pets", &pets);
+    c.insert("pet_types", &pet::types());
+    c.insert("errors", errors);
+
+    let r = metrics::render(tera, "owner/edit.html", &c)?;
+
+    Ok(Html::from(r))
+}
+
+pub async fn save(
+    Extension(tera): Extension<Tera>,
+    Extension(ctx): Extension<Arc<Context>>,
+    user: User,
+    Form(form): Form<OwnerForm>,
+) -> Result<Response, AppError> {
+    let owner_id: i32 = form
+        .id
+        .trim()
+        .parse()
+        .map_err(|_| AppError::Validation("Invalid owner id".to_string()))?;
+    let input = OwnerInput {
+        name: form.name.trim().to_string(),
+        phone: form.phone.trim().to_string(),
+        email: optional(&form.email),
+        address: optional(&form.address),
+    };
+    if let Err(e) = input.validate() {
+        let errors = e.downcast::<FieldErrors>()?;
+        let owner = Owner {
+            id: owner_id,
+            name: input.name,
+            phone: input.phone,
+            email: input.email,
+            address: input.address,
+        };
+        return Ok((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            render_edit(&tera, &ctx, owner, &errors).await?,
+        )
+            .into_response());
+    }
+
+    let db_conn = ctx.db_connection().await?;
+    let owner_id = db_conn
+        .interact(move |conn| {
+            conn.immediate_transaction(|conn| -> anyhow::Result<i32> {
+                let actor = Actor::from(&user);
+                if owner_id == 0 {
+                    let owner = NewOwner {
+                        name: input.name,
+                        phone: input.phone,
+                        email: input.email,
+                        address: input.address,
+                    };
+                    let owner = owner.save(conn)?;
+                    audit::created(conn, &actor, Entity::Owner, owner.id, &owner)?;
+                    Ok(owner.id)
+                } else {
+                    let Some(before) = Owner::select_by_id(conn, owner_id)? else {
+                        return Err(UserError::NotFound.into());
+                    };
+                    let o = Owner {
+                        name: input.name,
+                        phone: input.phone,
+                        email: input.email,
+                        address: input.address,
+                        ..before.clone()
+                    };
+                    o.clone().update(conn)?;
+                    audit::updated(conn, &actor, Entity::Owner, o.id, &before, &o)?;
+                    Ok(o.id)
+                }
+            })
+        })
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    Ok(Redirect::to(&format!("/owners/{owner_id}")).into_response())
+}
+
+pub async fn confirm_delete(
+    Extension(tera): Extension<Tera>,
+    Extension(ctx): Extension<Arc<Context>>,
+    Path(id): Path<i32>,
+) -> Result<Response, AppError> {
+    let db_conn = ctx.db_connection().await?;
+    let owner = db_conn
+        .interact(move |conn| Owner::select_by_id(conn, id))
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    let owner = owner.ok_or(AppError::NotFound)?;
+
+    Ok(Confirmation {
+        title: "Delete owner".to_string(),
+        message: format!("Delete {}?", owner.name),
+        action: format!("/owners/delete/{id}"),
+        cancel: format!("/owners/{id}"),
+    }
+    .render(&tera)?
+    .into_response())
+}
+
+pub async fn delete(
+    Extension(ctx): Extension<Arc<Context>>,
+    user: User,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let db_conn = ctx.db_connection().await?;
+    db_conn
+        .interact(move |conn| {
+            conn.immediate_transaction(|conn| -> anyhow::Result<()> {
+                if !Pet::select_by_owner(conn, id)?.is_empty() {
+                    return Err(UserError::Conflict(format!(
+                        "Owner {id} still has pets, remove or reassign them first"
+                    ))
+                    .into());
+                }
+                if let Some(owner) = Owner::select_by_id(conn, id)? {
+                    Owner::delete_by_id(conn, id)?;
+                    audit::deleted(conn, &Actor::from(&user), Entity::Owner, id, &owner)?;
+                }
+                Ok(())
+            })
+        })
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    Ok(Redirect::to("/owners"))
+}
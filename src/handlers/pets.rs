@@ -1,114 +1,129 @@
 use crate::{
     db::models::pet::{self, NewPet, Pet},
-    db::models::{user::User, vet::Vet},
-    AppError, Context,
+    db::models::{owner::Owner, user::User, vet::Vet, visit::Visit},
+    handlers::{confirm::Confirmation, visits::vet_names},
+    logic::{
+        audit::{self, Actor, Entity},
+        deletion,
+        validation::{FieldErrors, PetInput},
+    },
+    metrics, AppError, Context,
 };
 use axum::{
     extract::{Extension, Path, Query},
+    http::StatusCode,
     response::{Html, IntoResponse, Redirect, Response},
     Form,
 };
 use chrono::Utc;
 use diesel::RunQueryDsl;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, sync::Arc};
 use tera::Tera;
 
-#[derive(Deserialize)]
+/// Fields are kept as submitted, so a rejected form can be shown again as
+/// it was typed.
+#[derive(Default, Deserialize, Serialize)]
+#[serde(default)]
 pub struct PetForm {
-    pub id: i32,
+    pub id: String,
     pub name: String,
-    pub owner_name: String,
-    pub owner_phone: String,
-    pub age: i32,
-    pub current_vet: i32,
-    pub pet_type: i32,
+    pub owner_id: String,
+    pub age: String,
+    pub current_vet: String,
+    pub pet_type: String,
 }
 
-impl From<Form<PetForm>> for Pet {
-    fn from(form: Form<PetForm>) -> Pet {
-        Pet {
-            id: form.id,
-            name: form.name.clone(),
-            owner_name: form.owner_name.clone(),
-            owner_phone: form.owner_phone.clone(),
-            age: form.age,
-            vet_id: Some(form.current_vet),
-            pet_type: form.pet_type,
-            created_by: 0,
-            created_at: Utc::now().naive_utc(),
-        }
-    }
-}
-
-impl From<Form<PetForm>> for NewPet {
-    fn from(form: Form<PetForm>) -> NewPet {
-        NewPet {
-            name: form.name.clone(),
-            owner_name: form.owner_name.clone(),
-            owner_phone: form.owner_phone.clone(),
-            age: form.age,
-            vet_id: Some(form.current_vet),
-            pet_type: form.pet_type,
-            created_by: 0,
-            created_at: Utc::now().naive_utc(),
-        }
+impl PetForm {
+    /// The submitted pet, with errors for the values that did not parse.
+    fn parse(&self) -> (PetInput, FieldErrors) {
+        let mut errors = FieldErrors::default();
+        let vet_id = match self.current_vet.trim() {
+            "" | "0" => None,
+            vet_id => Some(errors.parse("vet_id", "Vet", vet_id)),
+        };
+        let input = PetInput {
+            name: self.name.trim().to_string(),
+            owner_id: errors.parse("owner_id", "Owner", &self.owner_id),
+            age: errors.parse("age", "Age", &self.age),
+            pet_type: errors.parse("pet_type", "Type", &self.pet_type),
+            vet_id,
+        };
+        (input, errors)
     }
 }
 
 pub async fn save(
+    Extension(tera): Extension<Tera>,
     Extension(ctx): Extension<Arc<Context>>,
     user: User,
-    pet_form: Form<PetForm>,
-) -> Result<impl IntoResponse, AppError> {
-    let db_conn = ctx.db_connection_pool.get().await?;
-
-    let pet_id = pet_form.id;
-    if pet_id == 0 {
-        let mut pet: NewPet = pet_form.into();
-        pet.created_by = user.id;
-        db_conn
-            .interact(move |conn| pet.save(conn))
-            .await
-            .map_err(|e| AppError {
-                inner: anyhow::Error::msg(e.to_string()),
-            })??;
-        // pet.save(conn)?;
-    } else {
-        let pet = db_conn
-            .interact(move |conn| Pet::select_by_id(conn, pet_id))
-            .await
-            .map_err(|e| AppError {
-                inner: anyhow::Error::msg(e.to_string()),
-            })?;
-
-        if pet.is_err() {
-            return Ok(Redirect::to("/pets"));
-        }
-
-        let mut pet = pet?;
+    Form(form): Form<PetForm>,
+) -> Result<Response, AppError> {
+    let pet_id: i32 = form
+        .id
+        .trim()
+        .parse()
+        .map_err(|_| AppError::Validation("Invalid pet id".to_string()))?;
+    let (input, mut errors) = form.parse();
+    let submitted = Pet {
+        id: pet_id,
+        name: input.name.clone(),
+        owner_id: input.owner_id,
+        age: input.age,
+        pet_type: input.pet_type,
+        vet_id: input.vet_id,
+        ..Pet::default()
+    };
 
-        pet.name = pet_form.name.clone();
-        pet.owner_name = pet_form.owner_name.clone();
-        pet.owner_phone = pet_form.owner_phone.clone();
-        pet.age = pet_form.age;
-        pet.pet_type = pet_form.pet_type;
+    let db_conn = ctx.db_connection().await?;
+    let result = db_conn
+        .interact(move |conn| {
+            conn.immediate_transaction(|conn| -> anyhow::Result<()> {
+                input.check(conn, &mut errors)?;
+                errors.into_result()?;
 
-        if pet_form.current_vet > 0 {
-            pet.vet_id = Some(pet_form.current_vet);
-        } else {
-            pet.vet_id = Some(0)
-        }
+                let actor = Actor::from(&user);
+                if pet_id == 0 {
+                    let pet = NewPet {
+                        name: input.name,
+                        owner_id: input.owner_id,
+                        age: input.age,
+                        pet_type: input.pet_type,
+                        vet_id: input.vet_id,
+                        created_at: Utc::now().naive_utc(),
+                        created_by: user.id,
+                    };
+                    let pet = pet.save(conn)?;
+                    audit::created(conn, &actor, Entity::Pet, pet.id, &pet)
+                } else {
+                    let before = Pet::select_by_id(conn, pet_id)?;
+                    let pet = Pet {
+                        name: input.name,
+                        owner_id: input.owner_id,
+                        age: input.age,
+                        pet_type: input.pet_type,
+                        vet_id: input.vet_id,
+                        ..before.clone()
+                    };
+                    pet.clone().update(conn)?;
+                    audit::updated(conn, &actor, Entity::Pet, pet.id, &before, &pet)
+                }
+            })
+        })
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))?;
 
-        db_conn
-            .interact(move |conn| pet.update(conn))
-            .await
-            .map_err(|e| AppError {
-                inner: anyhow::Error::msg(e.to_string()),
-            })??;
+    match result {
+        Ok(()) => Ok(Redirect::to("/pets").into_response()),
+        Err(e) => match e.downcast::<FieldErrors>() {
+            Ok(errors) => Ok((
+                StatusCode::UNPROCESSABLE_ENTITY,
+                render_edit(&tera, &ctx, submitted, Some(&form), &errors).await?,
+            )
+                .into_response()),
+            Err(e) => Err(e.into()),
+        },
     }
-
-    Ok(Redirect::to("/pets"))
 }
 
 pub async fn list(
@@ -116,7 +131,7 @@ pub async fn list(
     Extension(ctx): Extension<Arc<Context>>,
     Query(params): Query<HashMap<String, String>>,
 ) -> Result<Html<String>, AppError> {
-    let db_conn = ctx.db_connection_pool.get().await?;
+    let db_conn = ctx.db_connection().await?;
     let mut c = tera::Context::new();
 
     let pets = db_conn
@@ -128,84 +143,139 @@ pub async fn list(
             }
         })
         .await
-        .map_err(|e| AppError {
-            inner: anyhow::Error::msg(e.to_string()),
-        })??;
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    let owners = db_conn
+        .interact(Owner::owners)
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
 
     let types = pet::types();
 
     c.insert("pets", &pets);
+    c.insert("owners", &owner_map(owners));
     c.insert("pet_types", &types);
 
-    let r = tera.render("pet/list.html", &c)?;
+    let r = metrics::render(&tera, "pet/list.html", &c)?;
 
     Ok(Html::from(r))
 }
 
+pub async fn confirm_delete(
+    Extension(tera): Extension<Tera>,
+    Extension(ctx): Extension<Arc<Context>>,
+    Path(id): Path<i32>,
+) -> Result<Html<String>, AppError> {
+    let db_conn = ctx.db_connection().await?;
+    let pet = db_conn
+        .interact(move |conn| Pet::select_by_id(conn, id))
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    Confirmation {
+        title: "Delete pet".to_string(),
+        message: format!(
+            "Delete {} together with its visits and appointments?",
+            pet.name
+        ),
+        action: format!("/pets/delete/{id}"),
+        cancel: format!("/pets/{id}"),
+    }
+    .render(&tera)
+}
+
 pub async fn delete(
     Extension(ctx): Extension<Arc<Context>>,
+    user: User,
     Path(id): Path<i32>,
 ) -> Result<impl IntoResponse, AppError> {
-    let db_conn = ctx.db_connection_pool.get().await?;
+    let db_conn = ctx.db_connection().await?;
     db_conn
-        .interact(move |conn| Pet::delete_by_id(conn, id))
+        .interact(move |conn| {
+            conn.immediate_transaction(|conn| deletion::delete_pet(conn, &Actor::from(&user), id))
+        })
         .await
-        .map_err(|e| AppError {
-            inner: anyhow::Error::msg(e.to_string()),
-        })??;
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
 
     Ok(Redirect::to("/pets"))
 }
 
+/// Owners keyed by id, used by the templates to show each pet's owner.
+pub fn owner_map(owners: Vec<Owner>) -> HashMap<i32, Owner> {
+    owners.into_iter().map(|o| (o.id, o)).collect()
+}
+
 pub async fn get(
     Extension(tera): Extension<Tera>,
     Extension(ctx): Extension<Arc<Context>>,
     Path(id): Path<i32>,
+    Query(params): Query<HashMap<String, String>>,
 ) -> Result<Response, AppError> {
-    let db_conn = ctx.db_connection_pool.get().await?;
-
-    // TODO handle this correctly
-    let mut c = tera::Context::new();
-
     let pet = if id == 0 {
-        Pet::default()
+        // New pets can be started from an owner's page
+        let owner_id = params
+            .get("owner_id")
+            .and_then(|owner_id| owner_id.parse().ok())
+            .unwrap_or_default();
+        Pet {
+            owner_id,
+            ..Pet::default()
+        }
     } else {
+        let db_conn = ctx.db_connection().await?;
         db_conn
             .interact(move |conn| Pet::select_by_id(conn, id))
             .await
-            .map_err(|e| AppError {
-                inner: anyhow::Error::msg(e.to_string()),
-            })??
+            .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??
     };
 
-    let vets = db_conn
-        .interact(move |conn| Vet::all().load(conn))
-        .await
-        .map_err(|e| AppError {
-            inner: anyhow::Error::msg(e.to_string()),
-        })??;
+    Ok(render_edit(&tera, &ctx, pet, None, &FieldErrors::default())
+        .await?
+        .into_response())
+}
 
-    let types = pet::types();
+/// The edit page of `pet`, showing what was wrong with a rejected `form`.
+async fn render_edit(
+    tera: &Tera,
+    ctx: &Context,
+    pet: Pet,
+    form: Option<&PetForm>,
+    errors: &FieldErrors,
+) -> Result<Html<String>, AppError> {
+    let db_conn = ctx.db_connection().await?;
+    let mut c = tera::Context::new();
 
-    c.insert("pet_types", &types);
-    let vet = db_conn
-        .interact(move |conn| {
-            pet.vet_id
+    let id = pet.id;
+    let vet_id = pet.vet_id;
+    let (vets, owners, current_vet, visits) = db_conn
+        .interact(move |conn| -> anyhow::Result<_> {
+            let vets: Vec<Vet> = Vet::all().load(conn)?;
+            let owners = Owner::owners(conn)?;
+            let current_vet = vet_id
                 .map(|vet_id| Vet::select_by_id(conn, vet_id))
-                .transpose()
+                .transpose()?
+                .flatten();
+            let visits = Visit::select_by_pet(conn, id)?;
+            Ok((vets, owners, current_vet, visits))
         })
         .await
-        .map_err(|e| AppError {
-            inner: anyhow::Error::msg(e.to_string()),
-        })??;
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
 
-    if let Some(current_vet) = vet {
+    c.insert("pet_types", &pet::types());
+    c.insert("owners", &owners);
+    if let Some(current_vet) = current_vet {
         c.insert("current_vet", &current_vet);
     }
     c.insert("pet", &pet);
     c.insert("vets", &vets);
+    c.insert("visits", &visits);
+    c.insert("vet_names", &vet_names(&vets));
+    c.insert("errors", errors);
+    if let Some(form) = form {
+        c.insert("form", form);
+    }
 
-    let r = tera.render("pet/edit.html", &c)?;
+    let r = metrics::render(tera, "pet/edit.html", &c)?;
 
-    Ok(Html::from(r).into_response())
+    Ok(Html::from(r))
 }
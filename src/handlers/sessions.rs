@@ -0,0 +1,37 @@
+use crate::{db::models::user::User, handlers::auth, logic::sessions, metrics, AppError, Context};
+use axum::{
+    extract::{Extension, Path},
+    response::{Html, IntoResponse, Redirect},
+};
+use axum_extra::extract::cookie::CookieJar;
+use std::sync::Arc;
+use tera::Tera;
+
+/// "My sessions": every browser the logged in user is signed in with.
+pub async fn list(
+    Extension(tera): Extension<Tera>,
+    Extension(ctx): Extension<Arc<Context>>,
+    current_user: User,
+    jar: CookieJar,
+) -> Result<Html<String>, AppError> {
+    let mut c = tera::Context::new();
+
+    let current_key = jar.get(auth::SESSION_COOKIE).map(|cookie| cookie.value());
+    let sessions = sessions::list(ctx.sessions.as_ref(), current_user.id, current_key).await?;
+
+    c.insert("sessions", &sessions);
+    let r = metrics::render(&tera, "account/sessions.html", &c)?;
+
+    Ok(Html::from(r))
+}
+
+pub async fn revoke(
+    Extension(ctx): Extension<Arc<Context>>,
+    current_user: User,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    if sessions::revoke(ctx.sessions.as_ref(), current_user.id, &id).await? {
+        tracing::info!("{} revoked one of their sessions", current_user.username);
+    }
+    Ok(Redirect::to("/account/sessions"))
+}
@@ -0,0 +1,170 @@
+use crate::{
+    db::models::{user::User, user_totp::UserTotp},
+    logic::{audit::Actor, two_factor},
+    metrics, AppError, Context,
+};
+use axum::{
+    extract::{Extension, Form, Query},
+    response::{Html, IntoResponse, Redirect, Response},
+};
+use serde::Deserialize;
+use std::sync::Arc;
+use tera::Tera;
+
+#[derive(Deserialize)]
+pub struct CodeForm {
+    code: String,
+}
+
+#[derive(Deserialize)]
+pub struct SettingsParams {
+    error: Option<String>,
+}
+
+/// Two-factor settings of the logged in user: the QR code while enrolling,
+/// the remaining recovery codes once enabled.
+pub async fn settings(
+    Extension(tera): Extension<Tera>,
+    Extension(ctx): Extension<Arc<Context>>,
+    current_user: User,
+    Query(params): Query<SettingsParams>,
+) -> Result<Html<String>, AppError> {
+    let db_conn = ctx.db_connection().await?;
+    let mut c = tera::Context::new();
+
+    let user_id = current_user.id;
+    let (totp, remaining) = db_conn
+        .interact(move |conn| -> anyhow::Result<_> {
+            let totp = UserTotp::select_by_user_id(conn, user_id)?;
+            let remaining = two_factor::remaining_recovery_codes(conn, user_id)?;
+            Ok((totp, remaining))
+        })
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    match totp {
+        Some(totp) if totp.enabled => {
+            c.insert("enabled", &true);
+            c.insert("remaining_recovery_codes", &remaining);
+        }
+        Some(totp) => {
+            let uri = two_factor::provisioning_uri(&current_user.username, &totp.secret);
+            c.insert("qr_code", &two_factor::qr_code_svg(&uri)?);
+            c.insert("secret", &totp.secret);
+        }
+        None => {}
+    }
+    if params.error.is_some() {
+        c.insert(
+            "error",
+            "Invalid code, check the time of your device and try again",
+        );
+    }
+    let r = metrics::render(&tera, "account/two_factor.html", &c)?;
+
+    Ok(Html::from(r))
+}
+
+pub async fn setup(
+    Extension(ctx): Extension<Arc<Context>>,
+    current_user: User,
+) -> Result<impl IntoResponse, AppError> {
+    let db_conn = ctx.db_connection().await?;
+    db_conn
+        .interact(move |conn| two_factor::start_enrollment(conn, current_user.id))
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    Ok(Redirect::to("/account/two-factor"))
+}
+
+/// Finishes the enrollment and shows the recovery codes.
+pub async fn enable(
+    Extension(tera): Extension<Tera>,
+    Extension(ctx): Extension<Arc<Context>>,
+    current_user: User,
+    Form(form): Form<CodeForm>,
+) -> Result<impl IntoResponse, AppError> {
+    let db_conn = ctx.db_connection().await?;
+    let user_id = current_user.id;
+    let actor = Actor::from(&current_user);
+    let codes = db_conn
+        .interact(move |conn| two_factor::confirm_enrollment(conn, &actor, user_id, &form.code))
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    let Some(codes) = codes else {
+        return Ok(Redirect::to("/account/two-factor?error").into_response());
+    };
+    tracing::info!(
+        "Two-factor authentication enabled for {}",
+        current_user.username
+    );
+    render_recovery_codes(&tera, &codes)
+}
+
+/// Replaces the recovery codes, after checking a current code.
+pub async fn recovery_codes(
+    Extension(tera): Extension<Tera>,
+    Extension(ctx): Extension<Arc<Context>>,
+    current_user: User,
+    Form(form): Form<CodeForm>,
+) -> Result<impl IntoResponse, AppError> {
+    let db_conn = ctx.db_connection().await?;
+    let user_id = current_user.id;
+    let actor = Actor::from(&current_user);
+    let codes = db_conn
+        .interact(move |conn| -> anyhow::Result<_> {
+            if !two_factor::verify(conn, user_id, &form.code)? {
+                return Ok(None);
+            }
+            Ok(Some(two_factor::regenerate_recovery_codes(
+                conn, &actor, user_id,
+            )?))
+        })
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    match codes {
+        Some(codes) => render_recovery_codes(&tera, &codes),
+        None => Ok(Redirect::to("/account/two-factor?error").into_response()),
+    }
+}
+
+/// Turns two-factor login off, after checking a current code.
+pub async fn disable(
+    Extension(ctx): Extension<Arc<Context>>,
+    current_user: User,
+    Form(form): Form<CodeForm>,
+) -> Result<impl IntoResponse, AppError> {
+    let db_conn = ctx.db_connection().await?;
+    let user_id = current_user.id;
+    let actor = Actor::from(&current_user);
+    let disabled = db_conn
+        .interact(move |conn| -> anyhow::Result<_> {
+            if !two_factor::verify(conn, user_id, &form.code)? {
+                return Ok(false);
+            }
+            two_factor::disable(conn, &actor, user_id)?;
+            Ok(true)
+        })
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    if !disabled {
+        return Ok(Redirect::to("/account/two-factor?error"));
+    }
+    tracing::info!(
+        "Two-factor authentication disabled for {}",
+        current_user.username
+    );
+    Ok(Redirect::to("/account/two-factor"))
+}
+
+fn render_recovery_codes(tera: &Tera, codes: &[String]) -> Result<Response, AppError> {
+    let mut c = tera::Context::new();
+    c.insert("recovery_codes", codes);
+    let r = metrics::render(tera, "account/recovery_codes.html", &c)?;
+
+    Ok(Html::from(r).into_response())
+}
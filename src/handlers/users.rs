@@ -0,0 +1,211 @@
+use crate::{
+    db::models::{
+        user::{Role, User},
+        user_totp::UserTotp,
+    },
+    logic::{audit::Actor, lockout, two_factor, users},
+    metrics, AppError, Context,
+};
+use axum::{
+    extract::{Extension, Path},
+    response::{Html, IntoResponse, Redirect, Response},
+    Form,
+};
+use serde::Deserialize;
+use std::sync::Arc;
+use tera::Tera;
+
+#[derive(Deserialize)]
+pub struct UserForm {
+    id: i32,
+    username: String,
+    password: String,
+    role: Role,
+}
+
+pub async fn list(
+    Extension(tera): Extension<Tera>,
+    Extension(ctx): Extension<Arc<Context>>,
+) -> Result<Html<String>, AppError> {
+    let db_conn = ctx.db_connection().await?;
+    let mut c = tera::Context::new();
+
+    let (users, two_factor_ids) = db_conn
+        .interact(|conn| -> anyhow::Result<_> {
+            Ok((User::users(conn)?, UserTotp::enabled_user_ids(conn)?))
+        })
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    let mut locked = Vec::new();
+    for user in &users {
+        if lockout::is_locked(ctx.sessions.as_ref(), &user.username).await? {
+            locked.push(user.id);
+        }
+    }
+
+    c.insert("users", &users);
+    c.insert("locked", &locked);
+    c.insert("two_factor", &two_factor_ids);
+    let r = metrics::render(&tera, "user/list.html", &c)?;
+
+    Ok(Html::from(r))
+}
+
+pub async fn get(
+    Extension(tera): Extension<Tera>,
+    Extension(ctx): Extension<Arc<Context>>,
+    Path(id): Path<i32>,
+) -> Result<Response, AppError> {
+    let db_conn = ctx.db_connection().await?;
+    let mut c = tera::Context::new();
+
+    let user = db_conn
+        .interact(move |conn| User::select_by_id(conn, id))
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    let user = match user {
+        Some(user) => user,
+        None if id == 0 => User {
+            id: 0,
+            username: String::new(),
+            password: String::new(),
+            disabled: false,
+            role: Role::default(),
+        },
+        None => return Err(AppError::NotFound),
+    };
+
+    c.insert("user", &user);
+    c.insert("roles", &Role::ALL);
+    c.insert("min_password_length", &users::MIN_PASSWORD_LENGTH);
+    let r = metrics::render(&tera, "user/edit.html", &c)?;
+
+    Ok(Html::from(r).into_response())
+}
+
+pub async fn save(
+    Extension(ctx): Extension<Arc<Context>>,
+    current_user: User,
+    user_form: Form<UserForm>,
+) -> Result<impl IntoResponse, AppError> {
+    if current_user.id == user_form.id && user_form.role != current_user.role {
+        return Err(AppError::Validation(
+            "You cannot change your own role".to_string(),
+        ));
+    }
+
+    let db_conn = ctx.db_connection().await?;
+
+    let actor = Actor::from(&current_user);
+    db_conn
+        .interact(move |conn| -> anyhow::Result<()> {
+            if user_form.id == 0 {
+                users::create_user(
+                    conn,
+                    &actor,
+                    &user_form.username,
+                    &user_form.password,
+                    user_form.role,
+                )?;
+            } else {
+                users::set_role(conn, &actor, user_form.id, user_form.role)?;
+                if !user_form.password.is_empty() {
+                    users::reset_password(conn, &actor, user_form.id, &user_form.password)?;
+                }
+            }
+            Ok(())
+        })
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    Ok(Redirect::to("/users"))
+}
+
+pub async fn disable(
+    Extension(ctx): Extension<Arc<Context>>,
+    current_user: User,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    if current_user.id == id {
+        return Err(AppError::Validation(
+            "You cannot disable your own account".to_string(),
+        ));
+    }
+
+    set_disabled(&ctx, &current_user, id, true).await?;
+    ctx.sessions.remove_user_sessions(id).await?;
+    Ok(Redirect::to("/users"))
+}
+
+pub async fn enable(
+    Extension(ctx): Extension<Arc<Context>>,
+    current_user: User,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    set_disabled(&ctx, &current_user, id, false).await?;
+    Ok(Redirect::to("/users"))
+}
+
+/// Lifts a lockout caused by too many failed logins.
+pub async fn unlock(
+    Extension(ctx): Extension<Arc<Context>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let db_conn = ctx.db_connection().await?;
+    let user = db_conn
+        .interact(move |conn| User::select_by_id(conn, id))
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    if let Some(user) = user {
+        lockout::unlock(ctx.sessions.as_ref(), &user.username).await?;
+        tracing::info!("Unlocked user {}", user.username);
+    }
+    Ok(Redirect::to("/users"))
+}
+
+/// Ends every session of a user, who has to log in again.
+pub async fn logout(
+    Extension(ctx): Extension<Arc<Context>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    ctx.sessions.remove_user_sessions(id).await?;
+    tracing::info!("Logged out every session of user {id}");
+    Ok(Redirect::to("/users"))
+}
+
+/// Turns off two-factor login of a user who lost their device and recovery
+/// codes, letting them log in with the password alone and enroll again.
+pub async fn reset_two_factor(
+    Extension(ctx): Extension<Arc<Context>>,
+    current_user: User,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let db_conn = ctx.db_connection().await?;
+    let actor = Actor::from(&current_user);
+    db_conn
+        .interact(move |conn| two_factor::disable(conn, &actor, id))
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    tracing::info!("Reset two-factor authentication of user {id}");
+    Ok(Redirect::to("/users"))
+}
+
+async fn set_disabled(
+    ctx: &Context,
+    current_user: &User,
+    id: i32,
+    disabled: bool,
+) -> Result<(), AppError> {
+    let db_conn = ctx.db_connection().await?;
+    let actor = Actor::from(current_user);
+    db_conn
+        .interact(move |conn| users::set_disabled(conn, &actor, id, disabled))
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    Ok(())
+}
@@ -1,10 +1,22 @@
 use crate::{
-    db::models::vet::{NewVet, Vet},
-    AppError, Context,
+    db::models::{
+        calendar_feed::CalendarFeed,
+        user::User,
+        vet::{NewVet, Vet},
+    },
+    errors::UserError,
+    handlers::confirm::Confirmation,
+    logic::{
+        audit::{self, Actor, Entity},
+        deletion,
+        validation::{FieldErrors, VetInput},
+    },
+    metrics, AppError, Context,
 };
 use axum::{
     extract::{Extension, Path, Query},
-    response::{Html, IntoResponse, Redirect},
+    http::StatusCode,
+    response::{Html, IntoResponse, Redirect, Response},
     Form,
 };
 use serde::Deserialize;
@@ -13,38 +25,65 @@ use tracing::log::trace;
 
 use std::{collections::HashMap, sync::Arc};
 
-#[derive(Deserialize)]
+#[derive(Default, Deserialize)]
+#[serde(default)]
 pub struct VetForm {
-    id: i32,
+    id: String,
     name: String,
 }
 pub async fn save(
+    Extension(tera): Extension<Tera>,
     Extension(ctx): Extension<Arc<Context>>,
-    vet: Form<VetForm>,
-) -> Result<impl IntoResponse, AppError> {
-    let db_conn = ctx.db_connection_pool.get().await?;
+    user: User,
+    Form(form): Form<VetForm>,
+) -> Result<Response, AppError> {
+    let vet_id: i32 = form
+        .id
+        .trim()
+        .parse()
+        .map_err(|_| AppError::Validation("Invalid vet id".to_string()))?;
+    let input = VetInput {
+        name: form.name.trim().to_string(),
+    };
+    if let Err(e) = input.validate() {
+        let errors = e.downcast::<FieldErrors>()?;
+        let vet = Vet {
+            id: vet_id,
+            name: input.name,
+        };
+        return Ok((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            render_edit(&tera, &ctx, vet, &errors).await?,
+        )
+            .into_response());
+    }
 
+    let db_conn = ctx.db_connection().await?;
     db_conn
-        .interact(move |conn| -> anyhow::Result<()> {
-            if let Some(mut v) = Vet::select_by_id(conn, vet.id)? {
-                v.name = vet.name.clone();
-                v.update(conn)?;
-            } else {
-                // Adding a new one
-                let vet = NewVet {
-                    name: vet.name.clone(),
-                };
-                vet.save(conn)?;
-            }
-
-            Ok(())
+        .interact(move |conn| {
+            conn.immediate_transaction(|conn| -> anyhow::Result<()> {
+                let actor = Actor::from(&user);
+                if vet_id == 0 {
+                    let vet = NewVet { name: input.name };
+                    let vet = vet.save(conn)?;
+                    audit::created(conn, &actor, Entity::Vet, vet.id, &vet)?;
+                } else {
+                    let Some(before) = Vet::select_by_id(conn, vet_id)? else {
+                        return Err(UserError::NotFound.into());
+                    };
+                    let mut v = before.clone();
+                    v.name = input.name;
+                    v.clone().update(conn)?;
+                    audit::updated(conn, &actor, Entity::Vet, v.id, &before, &v)?;
+                }
+
+                Ok(())
+            })
         })
         .await
-        .map_err(|e| AppError {
-            inner: anyhow::Error::msg(e.to_string()),
-        })??;
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
 
-    Ok(Redirect::to("/vets"))
+    Ok(Redirect::to("/vets").into_response())
 }
 
 pub async fn list(
@@ -53,7 +92,7 @@ pub async fn list(
     Query(params): Query<HashMap<String, String>>,
 ) -> Result<Html<String>, AppError> {
     trace!("list reuested");
-    let db_conn = ctx.db_connection_pool.get().await?;
+    let db_conn = ctx.db_connection().await?;
 
     let mut c = tera::Context::new();
 
@@ -66,12 +105,10 @@ pub async fn list(
             }
         })
         .await
-        .map_err(|e| AppError {
-            inner: anyhow::Error::msg(e.to_string()),
-        })??;
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
 
     c.insert("vets", &vets);
-    let r = tera.render("vet/list.html", &c)?;
+    let r = metrics::render(&tera, "vet/list.html", &c)?;
 
     Ok(Html::from(r))
 }
@@ -80,41 +117,83 @@ pub async fn get(
     Extension(ctx): Extension<Arc<Context>>,
     Path(id): Path<i32>,
 ) -> Result<Html<String>, AppError> {
-    let db_conn = ctx.db_connection_pool.get().await?;
+    let vet = if id == 0 {
+        Vet::default()
+    } else {
+        let db_conn = ctx.db_connection().await?;
+        db_conn
+            .interact(move |conn| Vet::select_by_id(conn, id))
+            .await
+            .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??
+            .ok_or(AppError::NotFound)?
+    };
+
+    render_edit(&tera, &ctx, vet, &FieldErrors::default()).await
+}
 
+/// The edit page of `vet`, showing what was wrong with a rejected form.
+async fn render_edit(
+    tera: &Tera,
+    ctx: &Context,
+    vet: Vet,
+    errors: &FieldErrors,
+) -> Result<Html<String>, AppError> {
+    let db_conn = ctx.db_connection().await?;
     let mut c = tera::Context::new();
 
-    let mut vet = db_conn
-        .interact(move |conn| Vet::select_by_id(conn, id))
+    let id = vet.id;
+    let feed = db_conn
+        .interact(move |conn| CalendarFeed::select_by_vet(conn, id))
         .await
-        .map_err(|e| AppError {
-            inner: anyhow::Error::msg(e.to_string()),
-        })??;
-
-    if id == 0 {
-        vet = Some(Vet::default());
-    }
-    if vet.is_none() {
-        return Ok(Html::from("Vet not found".to_string()));
-    }
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
 
     c.insert("vet", &vet);
+    c.insert("has_feed", &feed.is_some());
+    c.insert("errors", errors);
 
-    let r = tera.render("vet/edit.html", &c)?;
+    let r = metrics::render(tera, "vet/edit.html", &c)?;
 
     Ok(Html::from(r))
 }
 
+pub async fn confirm_delete(
+    Extension(tera): Extension<Tera>,
+    Extension(ctx): Extension<Arc<Context>>,
+    Path(id): Path<i32>,
+) -> Result<Response, AppError> {
+    let db_conn = ctx.db_connection().await?;
+    let vet = db_conn
+        .interact(move |conn| Vet::select_by_id(conn, id))
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    let vet = vet.ok_or(AppError::NotFound)?;
+
+    Ok(Confirmation {
+        title: "Delete veterinary".to_string(),
+        message: format!(
+            "Delete {} together with their visits and appointments? \
+             Their pets are left without a current vet.",
+            vet.name
+        ),
+        action: format!("/vets/delete/{id}"),
+        cancel: format!("/vets/{id}"),
+    }
+    .render(&tera)?
+    .into_response())
+}
+
 pub async fn delete(
     Extension(ctx): Extension<Arc<Context>>,
+    user: User,
     Path(id): Path<i32>,
 ) -> Result<impl IntoResponse, AppError> {
-    let db_conn = ctx.db_connection_pool.get().await?;
+    let db_conn = ctx.db_connection().await?;
     db_conn
-        .interact(move |conn| Vet::delete_by_id(conn, id))
+        .interact(move |conn| {
+            conn.immediate_transaction(|conn| deletion::delete_vet(conn, &Actor::from(&user), id))
+        })
         .await
-        .map_err(|e| AppError {
-            inner: anyhow::Error::msg(e.to_string()),
-        })??;
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
     Ok(Redirect::to("/vets"))
 }
@@ -0,0 +1,266 @@
+use crate::{
+    db::models::{
+        pet::Pet,
+        user::User,
+        vet::Vet,
+        visit::{NewVisit, Visit},
+    },
+    errors::UserError,
+    handlers::confirm::Confirmation,
+    logic::{
+        audit::{self, Actor, Entity},
+        validation::{FieldErrors, VisitInput},
+    },
+    metrics, AppError, Context,
+};
+use axum::{
+    extract::{Extension, Path},
+    http::StatusCode,
+    response::{Html, IntoResponse, Redirect, Response},
+    Form,
+};
+use chrono::{NaiveDate, Utc};
+use serde::Deserialize;
+use std::{collections::HashMap, sync::Arc};
+use tera::Tera;
+
+#[derive(Deserialize)]
+pub struct VisitForm {
+    pub id: i32,
+    pub vet_id: i32,
+    pub visit_date: NaiveDate,
+    pub notes: String,
+}
+
+impl VisitForm {
+    fn notes(&self) -> Option<String> {
+        let notes = self.notes.trim();
+        if notes.is_empty() {
+            None
+        } else {
+            Some(notes.to_string())
+        }
+    }
+}
+
+/// Vet names keyed by id, used by the templates to label each visit.
+pub fn vet_names(vets: &[Vet]) -> HashMap<i32, String> {
+    vets.iter().map(|v| (v.id, v.name.clone())).collect()
+}
+
+pub async fn list(
+    Extension(tera): Extension<Tera>,
+    Extension(ctx): Extension<Arc<Context>>,
+    Path(pet_id): Path<i32>,
+) -> Result<Html<String>, AppError> {
+    let db_conn = ctx.db_connection().await?;
+    let mut c = tera::Context::new();
+
+    let (pet, visits, vets) = db_conn
+        .interact(move |conn| -> anyhow::Result<_> {
+            let pet = Pet::select_by_id(conn, pet_id)?;
+            let visits = Visit::select_by_pet(conn, pet_id)?;
+            let vets = Vet::vets(conn)?;
+            Ok((pet, visits, vets))
+        })
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    c.insert("pet", &pet);
+    c.insert("visits", &visits);
+    c.insert("vet_names", &vet_names(&vets));
+
+    let r = metrics::render(&tera, "visit/list.html", &c)?;
+
+    Ok(Html::from(r))
+}
+
+pub async fn get(
+    Extension(tera): Extension<Tera>,
+    Extension(ctx): Extension<Arc<Context>>,
+    Path((pet_id, id)): Path<(i32, i32)>,
+) -> Result<Response, AppError> {
+    let db_conn = ctx.db_connection().await?;
+
+    let (pet, visit) = db_conn
+        .interact(move |conn| -> anyhow::Result<_> {
+            let pet = Pet::select_by_id(conn, pet_id)?;
+            let visit = Visit::select_by_id(conn, id)?;
+            Ok((pet, visit))
+        })
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    let visit = if id == 0 {
+        Visit {
+            pet_id,
+            vet_id: pet.vet_id.unwrap_or_default(),
+            visit_date: Utc::now().date_naive(),
+            ..Visit::default()
+        }
+    } else {
+        visit
+            .filter(|visit| visit.pet_id == pet_id)
+            .ok_or(AppError::NotFound)?
+    };
+
+    Ok(
+        render_edit(&tera, &ctx, pet, visit, &FieldErrors::default())
+            .await?
+            .into_response(),
+    )
+}
+
+/// The edit page of `visit`, showing what was wrong with a rejected form.
+async fn render_edit(
+    tera: &Tera,
+    ctx: &Context,
+    pet: Pet,
+    visit: Visit,
+    errors: &FieldErrors,
+) -> Result<Html<String>, AppError> {
+    let db_conn = ctx.db_connection().await?;
+    let mut c = tera::Context::new();
+
+    let vets = db_conn
+        .interact(Vet::vets)
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    c.insert("pet", &pet);
+    c.insert("visit", &visit);
+    c.insert("vets", &vets);
+    c.insert("errors", errors);
+
+    let r = metrics::render(tera, "visit/edit.html", &c)?;
+
+    Ok(Html::from(r))
+}
+
+pub async fn save(
+    Extension(tera): Extension<Tera>,
+    Extension(ctx): Extension<Arc<Context>>,
+    user: User,
+    Path(pet_id): Path<i32>,
+    Form(visit_form): Form<VisitForm>,
+) -> Result<Response, AppError> {
+    let db_conn = ctx.db_connection().await?;
+
+    let input = VisitInput {
+        vet_id: visit_form.vet_id,
+        visit_date: visit_form.visit_date,
+        notes: visit_form.notes(),
+    };
+    let submitted = Visit {
+        id: visit_form.id,
+        pet_id,
+        vet_id: input.vet_id,
+        visit_date: input.visit_date,
+        notes: input.notes.clone(),
+    };
+    let id = visit_form.id;
+    let result = db_conn
+        .interact(move |conn| {
+            conn.immediate_transaction(|conn| -> anyhow::Result<()> {
+                let actor = Actor::from(&user);
+                // 404 for unknown pets
+                Pet::select_by_id(conn, pet_id)?;
+                input.validate(conn)?;
+
+                if id == 0 {
+                    let visit = NewVisit {
+                        pet_id,
+                        vet_id: input.vet_id,
+                        visit_date: input.visit_date,
+                        notes: input.notes,
+                    };
+                    let visit = visit.save(conn)?;
+                    audit::created(conn, &actor, Entity::Visit, visit.id, &visit)?;
+                    return Ok(());
+                }
+                match Visit::select_by_id(conn, id)? {
+                    Some(before) if before.pet_id == pet_id => {
+                        let v = Visit {
+                            vet_id: input.vet_id,
+                            visit_date: input.visit_date,
+                            notes: input.notes,
+                            ..before.clone()
+                        };
+                        v.clone().update(conn)?;
+                        audit::updated(conn, &actor, Entity::Visit, v.id, &before, &v)
+                    }
+                    Some(_) => Err(UserError::Validation(format!(
+                        "Visit {id} does not belong to pet {pet_id}"
+                    ))
+                    .into()),
+                    None => Err(UserError::NotFound.into()),
+                }
+            })
+        })
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))?;
+
+    if let Err(e) = result {
+        let errors = e.downcast::<FieldErrors>()?;
+        let pet = db_conn
+            .interact(move |conn| Pet::select_by_id(conn, pet_id))
+            .await
+            .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+        return Ok((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            render_edit(&tera, &ctx, pet, submitted, &errors).await?,
+        )
+            .into_response());
+    }
+
+    Ok(Redirect::to(&format!("/pets/{pet_id}/visits")).into_response())
+}
+
+pub async fn confirm_delete(
+    Extension(tera): Extension<Tera>,
+    Extension(ctx): Extension<Arc<Context>>,
+    Path((pet_id, id)): Path<(i32, i32)>,
+) -> Result<Response, AppError> {
+    let db_conn = ctx.db_connection().await?;
+    let visit = db_conn
+        .interact(move |conn| Visit::select_by_id(conn, id))
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    let visit = visit
+        .filter(|visit| visit.pet_id == pet_id)
+        .ok_or(AppError::NotFound)?;
+
+    Ok(Confirmation {
+        title: "Delete visit".to_string(),
+        message: format!("Delete the visit of {}?", visit.visit_date),
+        action: format!("/pets/{pet_id}/visits/delete/{id}"),
+        cancel: format!("/pets/{pet_id}/visits/{id}"),
+    }
+    .render(&tera)?
+    .into_response())
+}
+
+pub async fn delete(
+    Extension(ctx): Extension<Arc<Context>>,
+    user: User,
+    Path((pet_id, id)): Path<(i32, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    let db_conn = ctx.db_connection().await?;
+    db_conn
+        .interact(move |conn| {
+            conn.immediate_transaction(|conn| -> anyhow::Result<()> {
+                if let Some(visit) = Visit::select_by_id(conn, id)? {
+                    if visit.pet_id == pet_id {
+                        Visit::delete_by_id(conn, id)?;
+                        audit::deleted(conn, &Actor::from(&user), Entity::Visit, id, &visit)?;
+                    }
+                }
+                Ok(())
+            })
+        })
+        .await
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())))??;
+
+    Ok(Redirect::to(&format!("/pets/{pet_id}/visits")))
+}
@@ -0,0 +1,162 @@
+//! Log output, and the request ID tying a response to its log lines.
+//!
+//! Every request runs in a `request` span carrying its ID and, once known, the
+//! logged in user, so each line logged while handling it names both.
+//!
+//! With an `[otel]` config section the spans are also exported to an
+//! OpenTelemetry collector, together with the spans opened around database
+//! calls, session store commands and template rendering.
+
+use crate::settings::{LogFormat, Otel, Settings};
+use anyhow::Result;
+use axum::{
+    extract::{MatchedPath, Request},
+    http::{HeaderName, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::{
+    runtime,
+    trace::{self, Sampler, Tracer},
+    Resource,
+};
+use std::time::Duration;
+use tracing::{field, Span};
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Layer};
+use uuid::Uuid;
+
+pub static REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+/// Longest request ID taken over from a client or proxy.
+const MAX_REQUEST_ID_LENGTH: usize = 64;
+
+tokio::task_local! {
+    static REQUEST_ID: RequestId;
+}
+
+/// ID of one request, taken from its `X-Request-Id` header or generated.
+#[derive(Clone, Debug)]
+pub struct RequestId(pub String);
+
+pub fn init(settings: &Settings) -> Result<()> {
+    let fmt = tracing_subscriber::fmt::layer();
+    let fmt = match settings.log.format {
+        LogFormat::Pretty => fmt.boxed(),
+        LogFormat::Json => fmt
+            .json()
+            .flatten_event(true)
+            .with_current_span(true)
+            .with_span_list(false)
+            .boxed(),
+    };
+    let otel = match &settings.otel {
+        Some(otel) => Some(tracing_opentelemetry::layer().with_tracer(tracer(otel)?)),
+        None => None,
+    };
+
+    tracing_subscriber::registry()
+        .with(EnvFilter::from_default_env())
+        .with(fmt)
+        .with(otel)
+        .init();
+    Ok(())
+}
+
+/// Batches spans and sends them to the collector in the background.
+fn tracer(otel: &Otel) -> Result<Tracer> {
+    let exporter = opentelemetry_otlp::new_exporter()
+        .tonic()
+        .with_endpoint(&otel.endpoint)
+        .with_timeout(Duration::from_secs(5));
+    let config = trace::config()
+        .with_sampler(Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+            otel.sample_ratio,
+        ))))
+        .with_resource(Resource::new([KeyValue::new(
+            "service.name",
+            otel.service_name.clone(),
+        )]));
+
+    Ok(opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(exporter)
+        .with_trace_config(config)
+        .install_batch(runtime::Tokio)?)
+}
+
+/// Sends the spans still waiting in the batch, if traces are exported.
+pub fn shutdown() {
+    opentelemetry::global::shutdown_tracer_provider();
+}
+
+/// ID of the request being handled, for error pages.
+pub fn current_request_id() -> Option<String> {
+    REQUEST_ID.try_with(|id| id.0.clone()).ok()
+}
+
+/// Only IDs that are safe to log and echo are taken over from the client.
+fn is_valid(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= MAX_REQUEST_ID_LENGTH
+        && id
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.'))
+}
+
+/// Gives every request an ID, returned in the `X-Request-Id` response header.
+pub async fn request_id(mut request: Request, next: Next) -> Response {
+    let id = request
+        .headers()
+        .get(&REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|id| is_valid(id))
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+    let id = RequestId(id);
+    request.extensions_mut().insert(id.clone());
+
+    let mut response = REQUEST_ID.scope(id.clone(), next.run(request)).await;
+    if let Ok(value) = HeaderValue::from_str(&id.0) {
+        response
+            .headers_mut()
+            .insert(REQUEST_ID_HEADER.clone(), value);
+    }
+    response
+}
+
+/// Span of one request, see [`record_user`].
+pub fn make_span(request: &Request) -> Span {
+    let request_id = request
+        .extensions()
+        .get::<RequestId>()
+        .map(|id| id.0.as_str())
+        .unwrap_or_default();
+    // Traces are named by route rather than URI, like the metrics
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(MatchedPath::as_str)
+        .unwrap_or_default();
+    tracing::info_span!(
+        "request",
+        otel.name = %format_args!("{} {route}", request.method()),
+        otel.kind = "server",
+        request_id,
+        method = %request.method(),
+        // Without the query, which can carry secrets such as feed tokens
+        path = request.uri().path(),
+        user = field::Empty,
+        status = field::Empty,
+    )
+}
+
+/// Adds the response status to the request's span.
+pub fn record_status(response: &Response, _latency: Duration, span: &Span) {
+    span.record("status", response.status().as_u16());
+}
+
+/// Adds the authenticated user to the current request's span.
+pub fn record_user(username: &str) {
+    Span::current().record("user", username);
+}
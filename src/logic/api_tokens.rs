@@ -0,0 +1,54 @@
+use crate::db::models::{
+    api_token::{ApiToken, NewApiToken},
+    user::User,
+};
+use anyhow::Result;
+use chrono::Utc;
+use diesel::SqliteConnection;
+use rand::{distributions::Alphanumeric, Rng};
+use sha2::{Digest, Sha256};
+
+const TOKEN_PREFIX: &str = "pc_";
+
+pub fn hash_token(token: &str) -> String {
+    format!("{:x}", Sha256::digest(token.as_bytes()))
+}
+
+/// Issues a new token for `user_id`. The plain token is only returned here and
+/// cannot be recovered later.
+pub fn create_token(
+    conn: &mut SqliteConnection,
+    user_id: i32,
+    name: &str,
+) -> Result<(ApiToken, String)> {
+    let token: String = rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(40)
+        .map(char::from)
+        .collect();
+    let token = format!("{TOKEN_PREFIX}{token}");
+
+    let api_token = NewApiToken {
+        user_id,
+        name: name.to_string(),
+        token_hash: hash_token(&token),
+        created_at: Utc::now().naive_utc(),
+    }
+    .save(conn)?;
+
+    Ok((api_token, token))
+}
+
+/// Resolves a bearer token to its enabled owner, recording when it was last used.
+pub fn authenticate_token(conn: &mut SqliteConnection, token: &str) -> Result<Option<User>> {
+    let Some(api_token) = ApiToken::select_by_hash(conn, &hash_token(token))? else {
+        return Ok(None);
+    };
+
+    let user = User::select_by_id(conn, api_token.user_id)?.filter(|user| !user.disabled);
+    if user.is_some() {
+        ApiToken::touch(conn, api_token.id, Utc::now().naive_utc())?;
+    }
+
+    Ok(user)
+}
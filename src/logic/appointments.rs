@@ -0,0 +1,301 @@
+use crate::{
+    db::models::{
+        appointment::{Appointment, NewAppointment},
+        owner::Owner,
+        pet::Pet,
+        vet::Vet,
+    },
+    errors::UserError,
+    logic::{
+        audit::{self, Actor, Entity},
+        validation::FieldErrors,
+    },
+};
+use anyhow::Result;
+use chrono::{Datelike, Days, NaiveDate, NaiveDateTime};
+use diesel::SqliteConnection;
+use std::{collections::HashMap, fmt};
+
+/// Reasons an appointment cannot be booked, shown back to the user.
+#[derive(Debug)]
+pub enum SchedulingError {
+    EndsBeforeStart,
+    /// The vet already has an appointment occupying part of the slot.
+    Conflict(Appointment),
+}
+
+impl fmt::Display for SchedulingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchedulingError::EndsBeforeStart => {
+                f.write_str("An appointment must end after it starts")
+            }
+            SchedulingError::Conflict(conflicting) => write!(
+                f,
+                "The vet already has an appointment from {} to {}",
+                conflicting.starts_at.format("%Y-%m-%d %H:%M"),
+                conflicting.ends_at.format("%H:%M"),
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SchedulingError {}
+
+/// Fails with [`FieldErrors`] unless the pet and the vet exist.
+fn check_references(conn: &mut SqliteConnection, pet_id: i32, vet_id: i32) -> Result<()> {
+    let mut errors = FieldErrors::default();
+    if !Pet::exists(conn, pet_id)? {
+        errors.add("pet_id", "Choose an existing pet");
+    }
+    if Vet::select_by_id(conn, vet_id)?.is_none() {
+        errors.add("vet_id", "Choose an existing vet");
+    }
+    errors.into_result()
+}
+
+fn check_slot(
+    conn: &mut SqliteConnection,
+    vet_id: i32,
+    starts_at: NaiveDateTime,
+    ends_at: NaiveDateTime,
+    exclude_id: i32,
+) -> Result<()> {
+    if ends_at <= starts_at {
+        return Err(SchedulingError::EndsBeforeStart.into());
+    }
+
+    if let Some(conflicting) =
+        Appointment::select_overlapping(conn, vet_id, starts_at, ends_at, exclude_id)?
+            .into_iter()
+            .next()
+    {
+        return Err(SchedulingError::Conflict(conflicting).into());
+    }
+
+    Ok(())
+}
+
+/// Books a new appointment, failing with [`FieldErrors`] for an unknown pet or
+/// vet and with [`SchedulingError`] if the vet is busy.
+pub fn book(
+    conn: &mut SqliteConnection,
+    actor: &Actor,
+    appointment: NewAppointment,
+) -> Result<Appointment> {
+    // IMMEDIATE takes the write lock up front so two bookings cannot both pass the check
+    conn.immediate_transaction(|conn| {
+        check_references(conn, appointment.pet_id, appointment.vet_id)?;
+        if appointment.status.occupies_slot() {
+            check_slot(
+                conn,
+                appointment.vet_id,
+                appointment.starts_at,
+                appointment.ends_at,
+                0,
+            )?;
+        }
+        let appointment = appointment.save(conn)?;
+        audit::created(
+            conn,
+            actor,
+            Entity::Appointment,
+            appointment.id,
+            &appointment,
+        )?;
+        Ok(appointment)
+    })
+}
+
+/// Saves changes to an existing appointment with the same checks as [`book`].
+/// `appointment.sequence` must be the stored one; it is bumped here.
+pub fn reschedule(
+    conn: &mut SqliteConnection,
+    actor: &Actor,
+    mut appointment: Appointment,
+) -> Result<()> {
+    appointment.sequence += 1;
+    conn.immediate_transaction(|conn| {
+        let Some(before) = Appointment::select_by_id(conn, appointment.id)? else {
+            return Err(UserError::NotFound.into());
+        };
+        check_references(conn, appointment.pet_id, appointment.vet_id)?;
+        if appointment.status.occupies_slot() {
+            check_slot(
+                conn,
+                appointment.vet_id,
+                appointment.starts_at,
+                appointment.ends_at,
+                appointment.id,
+            )?;
+        }
+        appointment.clone().update(conn)?;
+        audit::updated(
+            conn,
+            actor,
+            Entity::Appointment,
+            appointment.id,
+            &before,
+            &appointment,
+        )
+    })
+}
+
+/// Pet labels such as "Felix (John Doe)" keyed by pet id.
+pub fn pet_labels(conn: &mut SqliteConnection) -> Result<HashMap<i32, String>> {
+    let owners: HashMap<i32, String> = Owner::owners(conn)?
+        .into_iter()
+        .map(|o| (o.id, o.name))
+        .collect();
+
+    Ok(Pet::pets(conn)?
+        .into_iter()
+        .map(|p| {
+            let owner = owners.get(&p.owner_id).map_or("", String::as_str);
+            (p.id, format!("{} ({owner})", p.name))
+        })
+        .collect())
+}
+
+/// Monday of the week containing `date`.
+pub fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Days::new(date.weekday().num_days_from_monday() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::models::appointment::AppointmentStatus;
+    use diesel::Connection;
+
+    /// A migrated in-memory database with the sample pets and vets.
+    fn connection() -> SqliteConnection {
+        let mut conn = SqliteConnection::establish(":memory:").unwrap();
+        crate::db::migrations::run_pending(&mut conn).unwrap();
+        conn
+    }
+
+    fn at(time: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(&format!("2026-10-19 {time}"), "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    fn appointment(vet_id: i32, starts: &str, ends: &str) -> NewAppointment {
+        NewAppointment {
+            pet_id: 1,
+            vet_id,
+            starts_at: at(starts),
+            ends_at: at(ends),
+            status: AppointmentStatus::Booked,
+            reason: None,
+            created_at: at("08:00"),
+            created_by: 1,
+        }
+    }
+
+    fn book_slot(conn: &mut SqliteConnection, vet_id: i32, starts: &str, ends: &str) -> Result<()> {
+        book(conn, &Actor::cli(), appointment(vet_id, starts, ends)).map(|_| ())
+    }
+
+    fn is_conflict(result: Result<()>) -> bool {
+        matches!(
+            result.map_err(|e| e.downcast::<SchedulingError>()),
+            Err(Ok(SchedulingError::Conflict(_)))
+        )
+    }
+
+    #[test]
+    fn adjacent_slots_can_be_booked() {
+        let conn = &mut connection();
+        book_slot(conn, 1, "10:00", "10:30").unwrap();
+        book_slot(conn, 1, "09:30", "10:00").unwrap();
+        book_slot(conn, 1, "10:30", "11:00").unwrap();
+    }
+
+    #[test]
+    fn overlapping_slots_of_the_same_vet_conflict() {
+        let conn = &mut connection();
+        book_slot(conn, 1, "10:00", "10:30").unwrap();
+        for (starts, ends) in [
+            ("10:00", "10:30"),
+            ("09:45", "10:15"),
+            ("10:15", "10:45"),
+            ("10:10", "10:20"),
+            ("09:00", "11:00"),
+        ] {
+            assert!(
+                is_conflict(book_slot(conn, 1, starts, ends)),
+                "{starts}-{ends}"
+            );
+        }
+        // Another vet is free at the same time
+        book_slot(conn, 2, "10:00", "10:30").unwrap();
+    }
+
+    #[test]
+    fn cancelled_slots_are_free() {
+        let conn = &mut connection();
+        let booked = book(conn, &Actor::cli(), appointment(1, "10:00", "10:30")).unwrap();
+        reschedule(
+            conn,
+            &Actor::cli(),
+            Appointment {
+                status: AppointmentStatus::Cancelled,
+                ..booked.clone()
+            },
+        )
+        .unwrap();
+        book_slot(conn, 1, "10:00", "10:30").unwrap();
+
+        // Reinstating the cancelled appointment would now double-book the vet
+        let reinstated = Appointment {
+            sequence: 1,
+            ..booked
+        };
+        assert!(is_conflict(reschedule(conn, &Actor::cli(), reinstated)));
+    }
+
+    #[test]
+    fn rescheduling_ignores_the_appointment_itself() {
+        let conn = &mut connection();
+        let booked = book(conn, &Actor::cli(), appointment(1, "10:00", "10:30")).unwrap();
+        let moved = Appointment {
+            ends_at: at("10:45"),
+            ..booked
+        };
+        reschedule(conn, &Actor::cli(), moved).unwrap();
+        assert!(is_conflict(book_slot(conn, 1, "10:30", "11:00")));
+    }
+
+    #[test]
+    fn slots_must_end_after_they_start() {
+        let conn = &mut connection();
+        let result =
+            book_slot(conn, 1, "10:00", "10:00").map_err(|e| e.downcast::<SchedulingError>());
+        assert!(matches!(result, Err(Ok(SchedulingError::EndsBeforeStart))));
+    }
+
+    #[test]
+    fn pet_and_vet_must_exist() {
+        let conn = &mut connection();
+        let orphan = NewAppointment {
+            pet_id: 0,
+            ..appointment(999, "10:00", "10:30")
+        };
+        let errors = book(conn, &Actor::cli(), orphan)
+            .unwrap_err()
+            .downcast::<FieldErrors>()
+            .unwrap();
+        assert_eq!(
+            errors.to_string(),
+            "Choose an existing pet, Choose an existing vet"
+        );
+        assert!(Appointment::select_by_vet(conn, 999).unwrap().is_empty());
+
+        let missing = Appointment {
+            id: 42,
+            ..Appointment::default()
+        };
+        let e = reschedule(conn, &Actor::cli(), missing).unwrap_err();
+        assert!(matches!(e.downcast::<UserError>(), Ok(UserError::NotFound)));
+    }
+}
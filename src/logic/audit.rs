@@ -0,0 +1,234 @@
+//! Audit trail of every create, update and delete: who changed which record,
+//! when, and which fields.
+//!
+//! Entries are written on the connection making the change, inside its
+//! transaction, so a change is never stored without its entry or the other
+//! way round.
+
+use crate::db::models::{audit_log::NewAuditLog, user::User};
+use anyhow::Result;
+use chrono::Utc;
+use diesel::SqliteConnection;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::str::FromStr;
+
+/// Fields whose values are never copied into the log.
+const REDACTED_FIELDS: [&str; 3] = ["password", "secret", "token_hash"];
+const REDACTED: &str = "[redacted]";
+
+/// Who made a change.
+#[derive(Clone, Debug)]
+pub struct Actor {
+    pub user_id: Option<i32>,
+    pub name: String,
+}
+
+impl Actor {
+    /// Changes made with the `petclinic` command line tool.
+    pub fn cli() -> Self {
+        Actor {
+            user_id: None,
+            name: "cli".to_string(),
+        }
+    }
+}
+
+impl From<&User> for Actor {
+    fn from(user: &User) -> Self {
+        Actor {
+            user_id: Some(user.id),
+            name: user.username.clone(),
+        }
+    }
+}
+
+/// Kind of record an entry is about.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Entity {
+    Appointment,
+    CalendarFeed,
+    Owner,
+    Pet,
+    TwoFactor,
+    User,
+    Vet,
+    Visit,
+}
+
+impl Entity {
+    pub const ALL: [Entity; 8] = [
+        Entity::Appointment,
+        Entity::CalendarFeed,
+        Entity::Owner,
+        Entity::Pet,
+        Entity::TwoFactor,
+        Entity::User,
+        Entity::Vet,
+        Entity::Visit,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Entity::Appointment => "appointment",
+            Entity::CalendarFeed => "calendar_feed",
+            Entity::Owner => "owner",
+            Entity::Pet => "pet",
+            Entity::TwoFactor => "two_factor",
+            Entity::User => "user",
+            Entity::Vet => "vet",
+            Entity::Visit => "visit",
+        }
+    }
+}
+
+impl FromStr for Entity {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Entity::ALL
+            .into_iter()
+            .find(|entity| entity.as_str() == s)
+            .ok_or_else(|| anyhow::anyhow!("Unknown entity type: {s}"))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    Create,
+    Update,
+    Delete,
+}
+
+impl Action {
+    pub const ALL: [Action; 3] = [Action::Create, Action::Update, Action::Delete];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Action::Create => "create",
+            Action::Update => "update",
+            Action::Delete => "delete",
+        }
+    }
+}
+
+impl FromStr for Action {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Action::ALL
+            .into_iter()
+            .find(|action| action.as_str() == s)
+            .ok_or_else(|| anyhow::anyhow!("Unknown action: {s}"))
+    }
+}
+
+fn fields(value: &impl Serialize) -> Result<Map<String, Value>> {
+    match serde_json::to_value(value)? {
+        Value::Object(map) => Ok(map),
+        other => anyhow::bail!("Audited values must serialize to objects, not {other}"),
+    }
+}
+
+/// `{"field": {"before": .., "after": ..}}` for every field that differs.
+fn diff(before: &Map<String, Value>, after: &Map<String, Value>) -> Map<String, Value> {
+    let mut names: Vec<&String> = before.keys().chain(after.keys()).collect();
+    names.sort();
+    names.dedup();
+
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let old = before.get(name).unwrap_or(&Value::Null);
+            let new = after.get(name).unwrap_or(&Value::Null);
+            if old == new {
+                return None;
+            }
+            let change = if REDACTED_FIELDS.contains(&name.as_str()) {
+                json!({ "before": REDACTED, "after": REDACTED })
+            } else {
+                json!({ "before": old, "after": new })
+            };
+            Some((name.clone(), change))
+        })
+        .collect()
+}
+
+/// Writes one entry with already computed `changes`.
+pub fn record(
+    conn: &mut SqliteConnection,
+    actor: &Actor,
+    entity: Entity,
+    entity_id: i32,
+    action: Action,
+    changes: Map<String, Value>,
+) -> Result<()> {
+    NewAuditLog {
+        actor_id: actor.user_id,
+        actor_name: actor.name.clone(),
+        entity_type: entity.as_str().to_string(),
+        entity_id,
+        action: action.as_str().to_string(),
+        changes: Value::Object(changes).to_string(),
+        created_at: Utc::now().naive_utc(),
+    }
+    .save(conn)?;
+
+    Ok(())
+}
+
+pub fn created(
+    conn: &mut SqliteConnection,
+    actor: &Actor,
+    entity: Entity,
+    entity_id: i32,
+    after: &impl Serialize,
+) -> Result<()> {
+    let changes = diff(&Map::new(), &fields(after)?);
+    record(conn, actor, entity, entity_id, Action::Create, changes)
+}
+
+/// Logs the fields changed between `before` and `after`, if any.
+pub fn updated<T: Serialize>(
+    conn: &mut SqliteConnection,
+    actor: &Actor,
+    entity: Entity,
+    entity_id: i32,
+    before: &T,
+    after: &T,
+) -> Result<()> {
+    let changes = diff(&fields(before)?, &fields(after)?);
+    if changes.is_empty() {
+        return Ok(());
+    }
+    record(conn, actor, entity, entity_id, Action::Update, changes)
+}
+
+/// Logs that a secret such as a password changed, without its values.
+pub fn secret_changed(
+    conn: &mut SqliteConnection,
+    actor: &Actor,
+    entity: Entity,
+    entity_id: i32,
+    field: &str,
+) -> Result<()> {
+    let mut changes = Map::new();
+    changes.insert(
+        field.to_string(),
+        json!({ "before": REDACTED, "after": REDACTED }),
+    );
+    record(conn, actor, entity, entity_id, Action::Update, changes)
+}
+
+pub fn deleted(
+    conn: &mut SqliteConnection,
+    actor: &Actor,
+    entity: Entity,
+    entity_id: i32,
+    before: &impl Serialize,
+) -> Result<()> {
+    let changes = diff(&fields(before)?, &Map::new());
+    record(conn, actor, entity, entity_id, Action::Delete, changes)
+}
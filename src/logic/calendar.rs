@@ -0,0 +1,327 @@
+//! iCalendar (RFC 5545) feeds of a vet's appointments and visits, read by
+//! calendar clients through a secret per-vet URL instead of the login session.
+
+use crate::{
+    db::models::{
+        appointment::{Appointment, AppointmentStatus},
+        calendar_feed::{CalendarFeed, NewCalendarFeed},
+        vet::Vet,
+        visit::Visit,
+    },
+    logic::{
+        api_tokens::hash_token,
+        appointments::pet_labels,
+        audit::{self, Actor, Entity},
+    },
+};
+use anyhow::Result;
+use chrono::{NaiveDate, NaiveDateTime, Utc};
+use diesel::SqliteConnection;
+use rand::{distributions::Alphanumeric, Rng};
+use serde_json::json;
+use subtle::ConstantTimeEq;
+
+const PRODID: &str = "-//petclinic//vet schedule//EN";
+const UID_DOMAIN: &str = "petclinic";
+/// Lines longer than this many octets are folded (RFC 5545 section 3.1).
+const MAX_LINE_OCTETS: usize = 75;
+
+/// Replaces the feed token of `vet_id`, invalidating any previous feed URL.
+/// The plain token is only returned here and cannot be recovered later.
+pub fn create_feed_token(
+    conn: &mut SqliteConnection,
+    actor: &Actor,
+    vet_id: i32,
+) -> Result<String> {
+    let token: String = rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(40)
+        .map(char::from)
+        .collect();
+
+    conn.immediate_transaction(|conn| {
+        let replaced = CalendarFeed::delete_by_vet(conn, vet_id)? > 0;
+        NewCalendarFeed {
+            vet_id,
+            token_hash: hash_token(&token),
+            created_at: Utc::now().naive_utc(),
+        }
+        .save(conn)?;
+        // Feeds are logged under their vet, a vet has at most one
+        if replaced {
+            audit::secret_changed(conn, actor, Entity::CalendarFeed, vet_id, "token")
+        } else {
+            audit::created(
+                conn,
+                actor,
+                Entity::CalendarFeed,
+                vet_id,
+                &json!({ "vet_id": vet_id }),
+            )
+        }
+    })?;
+
+    Ok(token)
+}
+
+/// Whether `token` currently grants access to the feed of `vet_id`.
+pub fn verify_feed_token(conn: &mut SqliteConnection, vet_id: i32, token: &str) -> Result<bool> {
+    Ok(
+        CalendarFeed::select_by_vet(conn, vet_id)?.is_some_and(|feed| {
+            bool::from(
+                feed.token_hash
+                    .as_bytes()
+                    .ct_eq(hash_token(token).as_bytes()),
+            )
+        }),
+    )
+}
+
+/// Renders the whole schedule of `vet_id` as a `VCALENDAR`, or `None` if the
+/// vet does not exist.
+///
+/// Every event keeps a UID derived from its row id, so clients refreshing the
+/// feed update rescheduled appointments in place and show cancelled ones as
+/// such instead of duplicating them.
+pub fn vet_calendar(conn: &mut SqliteConnection, vet_id: i32) -> Result<Option<String>> {
+    let Some(vet) = Vet::select_by_id(conn, vet_id)? else {
+        return Ok(None);
+    };
+    let appointments = Appointment::select_by_vet(conn, vet_id)?;
+    let visits = Visit::select_by_vet(conn, vet_id)?;
+    let pets = pet_labels(conn)?;
+
+    let stamp = format_date_time(Utc::now().naive_utc()) + "Z";
+    let mut ics = String::new();
+
+    write_line(&mut ics, "BEGIN:VCALENDAR");
+    write_line(&mut ics, "VERSION:2.0");
+    write_line(&mut ics, &format!("PRODID:{PRODID}"));
+    write_line(&mut ics, "CALSCALE:GREGORIAN");
+    write_line(&mut ics, "METHOD:PUBLISH");
+    write_line(&mut ics, &format!("X-WR-CALNAME:{}", escape(&vet.name)));
+
+    for appointment in appointments {
+        let pet = pets.get(&appointment.pet_id).map_or("", String::as_str);
+        let (summary, status) = match appointment.status {
+            AppointmentStatus::Cancelled => (pet.to_string(), "CANCELLED"),
+            AppointmentStatus::Booked => (pet.to_string(), "CONFIRMED"),
+            other => (format!("{pet} [{other}]"), "CONFIRMED"),
+        };
+
+        write_line(&mut ics, "BEGIN:VEVENT");
+        write_line(
+            &mut ics,
+            &format!("UID:appointment-{}@{UID_DOMAIN}", appointment.id),
+        );
+        write_line(&mut ics, &format!("DTSTAMP:{stamp}"));
+        write_line(
+            &mut ics,
+            &format!("DTSTART:{}", format_date_time(appointment.starts_at)),
+        );
+        write_line(
+            &mut ics,
+            &format!("DTEND:{}", format_date_time(appointment.ends_at)),
+        );
+        write_line(&mut ics, &format!("SEQUENCE:{}", appointment.sequence));
+        write_line(&mut ics, &format!("STATUS:{status}"));
+        write_line(&mut ics, &format!("SUMMARY:{}", escape(&summary)));
+        if let Some(reason) = &appointment.reason {
+            write_line(&mut ics, &format!("DESCRIPTION:{}", escape(reason)));
+        }
+        write_line(&mut ics, "END:VEVENT");
+    }
+
+    for visit in visits {
+        let pet = pets.get(&visit.pet_id).map_or("", String::as_str);
+
+        write_line(&mut ics, "BEGIN:VEVENT");
+        write_line(&mut ics, &format!("UID:visit-{}@{UID_DOMAIN}", visit.id));
+        write_line(&mut ics, &format!("DTSTAMP:{stamp}"));
+        write_line(
+            &mut ics,
+            &format!("DTSTART;VALUE=DATE:{}", format_date(visit.visit_date)),
+        );
+        write_line(&mut ics, "TRANSP:TRANSPARENT");
+        write_line(
+            &mut ics,
+            &format!("SUMMARY:{}", escape(&format!("Visit: {pet}"))),
+        );
+        if let Some(notes) = &visit.notes {
+            write_line(&mut ics, &format!("DESCRIPTION:{}", escape(notes)));
+        }
+        write_line(&mut ics, "END:VEVENT");
+    }
+
+    write_line(&mut ics, "END:VCALENDAR");
+
+    Ok(Some(ics))
+}
+
+/// Clinic-local "floating" time, since stored times carry no time zone.
+fn format_date_time(value: NaiveDateTime) -> String {
+    value.format("%Y%m%dT%H%M%S").to_string()
+}
+
+fn format_date(value: NaiveDate) -> String {
+    value.format("%Y%m%d").to_string()
+}
+
+/// Escapes a TEXT property value.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Appends a content line terminated by CRLF, folding it without splitting
+/// multi-byte characters.
+fn write_line(out: &mut String, line: &str) {
+    let mut octets = 0;
+    for c in line.chars() {
+        if octets + c.len_utf8() > MAX_LINE_OCTETS {
+            out.push_str("\r\n ");
+            octets = 1;
+        }
+        out.push(c);
+        octets += c.len_utf8();
+    }
+    out.push_str("\r\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{db::models::appointment::NewAppointment, logic::appointments};
+    use diesel::Connection;
+
+    fn connection() -> SqliteConnection {
+        let mut conn = SqliteConnection::establish(":memory:").unwrap();
+        crate::db::migrations::run_pending(&mut conn).unwrap();
+        conn
+    }
+
+    fn at(time: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(&format!("2026-10-19 {time}"), "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    /// The unfolded content lines of the event with `uid`.
+    fn event(ics: &str, uid: &str) -> Vec<String> {
+        let unfolded = ics.replace("\r\n ", "");
+        let lines: Vec<&str> = unfolded.split("\r\n").collect();
+        let start = lines
+            .iter()
+            .position(|line| *line == format!("UID:{uid}"))
+            .unwrap_or_else(|| panic!("no event {uid}"));
+        lines[start..]
+            .iter()
+            .take_while(|line| **line != "END:VEVENT")
+            .map(|line| line.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn text_values_are_escaped() {
+        assert_eq!(escape("Rex; the dog, again"), r"Rex\; the dog\, again");
+        assert_eq!(escape("a\\b"), "a\\\\b");
+        assert_eq!(escape("line one\r\nline two"), "line one\\nline two");
+        assert_eq!(escape("Kätzchen"), "Kätzchen");
+    }
+
+    #[test]
+    fn short_lines_are_not_folded() {
+        let mut out = String::new();
+        write_line(&mut out, &"a".repeat(MAX_LINE_OCTETS));
+        assert_eq!(out, format!("{}\r\n", "a".repeat(MAX_LINE_OCTETS)));
+    }
+
+    #[test]
+    fn long_lines_are_folded_at_75_octets() {
+        let mut out = String::new();
+        write_line(&mut out, &"a".repeat(200));
+        let lines: Vec<&str> = out.trim_end_matches("\r\n").split("\r\n").collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines.iter().all(|line| line.len() <= MAX_LINE_OCTETS));
+        assert!(lines[1..].iter().all(|line| line.starts_with(' ')));
+        assert_eq!(out.replace("\r\n ", ""), format!("{}\r\n", "a".repeat(200)));
+    }
+
+    #[test]
+    fn folding_keeps_multi_byte_characters_whole() {
+        // 74 octets leave no room for the two octets of 'ä' on the first line
+        let line = format!("{}ä{}", "a".repeat(74), "b".repeat(10));
+        let mut out = String::new();
+        write_line(&mut out, &line);
+        let lines: Vec<&str> = out.trim_end_matches("\r\n").split("\r\n").collect();
+        assert_eq!(lines[0], "a".repeat(74));
+        assert!(lines[1].starts_with(" ä"));
+        assert!(lines.iter().all(|line| line.len() <= MAX_LINE_OCTETS));
+        assert_eq!(out.replace("\r\n ", ""), format!("{line}\r\n"));
+
+        // Exactly at the limit the character still fits
+        let line = format!("{}ä", "a".repeat(73));
+        let mut out = String::new();
+        write_line(&mut out, &line);
+        assert_eq!(out, format!("{line}\r\n"));
+    }
+
+    #[test]
+    fn unknown_vets_have_no_calendar() {
+        assert_eq!(vet_calendar(&mut connection(), 999).unwrap(), None);
+    }
+
+    #[test]
+    fn events_keep_their_uid_and_show_cancellations() {
+        let mut conn = connection();
+        let actor = Actor::cli();
+        let appointment = appointments::book(
+            &mut conn,
+            &actor,
+            NewAppointment {
+                pet_id: 1,
+                vet_id: 2,
+                starts_at: at("09:00"),
+                ends_at: at("09:30"),
+                status: AppointmentStatus::Booked,
+                reason: Some("Vaccination, yearly".to_string()),
+                created_at: at("08:00"),
+                created_by: 1,
+            },
+        )
+        .unwrap();
+        let uid = format!("appointment-{}@{UID_DOMAIN}", appointment.id);
+
+        let ics = vet_calendar(&mut conn, 2).unwrap().unwrap();
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        let booked = event(&ics, &uid);
+        assert!(booked.contains(&"DTSTART:20261019T090000".to_string()));
+        assert!(booked.contains(&"STATUS:CONFIRMED".to_string()));
+        assert!(booked.contains(&"SEQUENCE:0".to_string()));
+        assert!(booked.contains(&"DESCRIPTION:Vaccination\\, yearly".to_string()));
+
+        let cancelled = Appointment {
+            starts_at: at("10:00"),
+            ends_at: at("10:30"),
+            status: AppointmentStatus::Cancelled,
+            ..appointment
+        };
+        appointments::reschedule(&mut conn, &actor, cancelled).unwrap();
+
+        let ics = vet_calendar(&mut conn, 2).unwrap().unwrap();
+        assert_eq!(ics.matches(&format!("UID:{uid}")).count(), 1);
+        let cancelled = event(&ics, &uid);
+        assert!(cancelled.contains(&"DTSTART:20261019T100000".to_string()));
+        assert!(cancelled.contains(&"STATUS:CANCELLED".to_string()));
+        assert!(cancelled.contains(&"SEQUENCE:1".to_string()));
+    }
+}
@@ -0,0 +1,91 @@
+//! Deletes of pets and vets together with the rows referring to them.
+//!
+//! The schema declares `ON DELETE CASCADE`, but SQLite only acts on it with
+//! `PRAGMA foreign_keys` enabled, which this app leaves off. Dependent rows
+//! are removed here instead, in the caller's transaction and each with its
+//! own audit entry.
+
+use crate::{
+    db::models::{
+        appointment::Appointment, calendar_feed::CalendarFeed, pet::Pet, vet::Vet, visit::Visit,
+    },
+    logic::audit::{self, Actor, Entity},
+};
+use anyhow::Result;
+use diesel::SqliteConnection;
+use serde_json::json;
+
+/// Deletes pet `id` with its visits and appointments.
+pub fn delete_pet(conn: &mut SqliteConnection, actor: &Actor, id: i32) -> Result<()> {
+    let pet = Pet::select_by_id(conn, id)?;
+    let visits = Visit::select_by_pet(conn, id)?;
+    delete_visits(conn, actor, visits)?;
+    let appointments = Appointment::select_by_pet(conn, id)?;
+    delete_appointments(conn, actor, appointments)?;
+
+    Pet::delete_by_id(conn, id)?;
+    audit::deleted(conn, actor, Entity::Pet, id, &pet)
+}
+
+/// Deletes vet `id` with their visits, appointments and calendar feed. Pets
+/// they were the current vet of are left without one. Returns whether the
+/// vet existed.
+pub fn delete_vet(conn: &mut SqliteConnection, actor: &Actor, id: i32) -> Result<bool> {
+    let Some(vet) = Vet::select_by_id(conn, id)? else {
+        return Ok(false);
+    };
+    let visits = Visit::select_by_vet(conn, id)?;
+    delete_visits(conn, actor, visits)?;
+    let appointments = Appointment::select_by_vet(conn, id)?;
+    delete_appointments(conn, actor, appointments)?;
+
+    for before in Pet::select_by_vet(conn, id)? {
+        let pet = Pet {
+            vet_id: None,
+            ..before.clone()
+        };
+        pet.clone().update(conn)?;
+        audit::updated(conn, actor, Entity::Pet, pet.id, &before, &pet)?;
+    }
+
+    // Feeds are logged under their vet, see `calendar::create_feed_token`
+    if CalendarFeed::delete_by_vet(conn, id)? > 0 {
+        audit::deleted(
+            conn,
+            actor,
+            Entity::CalendarFeed,
+            id,
+            &json!({ "vet_id": id }),
+        )?;
+    }
+
+    Vet::delete_by_id(conn, id)?;
+    audit::deleted(conn, actor, Entity::Vet, id, &vet)?;
+    Ok(true)
+}
+
+fn delete_visits(conn: &mut SqliteConnection, actor: &Actor, visits: Vec<Visit>) -> Result<()> {
+    for visit in visits {
+        Visit::delete_by_id(conn, visit.id)?;
+        audit::deleted(conn, actor, Entity::Visit, visit.id, &visit)?;
+    }
+    Ok(())
+}
+
+fn delete_appointments(
+    conn: &mut SqliteConnection,
+    actor: &Actor,
+    appointments: Vec<Appointment>,
+) -> Result<()> {
+    for appointment in appointments {
+        Appointment::delete_by_id(conn, appointment.id)?;
+        audit::deleted(
+            conn,
+            actor,
+            Entity::Appointment,
+            appointment.id,
+            &appointment,
+        )?;
+    }
+    Ok(())
+}
@@ -0,0 +1,207 @@
+//! Throttling of password guessing on the login form.
+//!
+//! Failed logins are counted per username and per client address in the
+//! session store, the latter only when the address is known. After a few free attempts every further one has to wait
+//! twice as long as the previous, and a username with too many failures is
+//! locked until the counter expires or an admin unlocks it.
+
+use crate::session::{FailedLogins, SessionStore};
+use anyhow::Result;
+use chrono::Utc;
+use std::{net::IpAddr, time::Duration};
+
+/// Counters are forgotten after this long without another failure.
+const WINDOW: Duration = Duration::from_secs(15 * 60);
+/// Failures allowed before any backoff applies.
+const FREE_ATTEMPTS: u32 = 3;
+const MAX_BACKOFF: Duration = Duration::from_secs(5 * 60);
+/// Failures of one username that lock the account for the rest of [`WINDOW`].
+const LOCKOUT_THRESHOLD: u32 = 10;
+
+/// Why a login attempt is refused before the password is even checked.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LoginBlock {
+    /// Too many recent failures, retry after the given delay.
+    Backoff(Duration),
+    /// The account is locked for the given time.
+    Locked(Duration),
+}
+
+fn user_key(username: &str) -> String {
+    format!("login_failures:user:{}", username.trim().to_lowercase())
+}
+
+fn ip_key(ip: IpAddr) -> String {
+    format!("login_failures:ip:{ip}")
+}
+
+fn backoff(count: u32) -> Duration {
+    match count.checked_sub(FREE_ATTEMPTS) {
+        None | Some(0) => Duration::ZERO,
+        Some(excess) => Duration::from_secs(1 << (excess - 1).min(16)).min(MAX_BACKOFF),
+    }
+}
+
+/// Time left until `delay` has passed since the last failure.
+fn remaining(failed: &FailedLogins, delay: Duration) -> Option<Duration> {
+    let elapsed = (Utc::now() - failed.last_failed_at)
+        .to_std()
+        .unwrap_or(Duration::ZERO);
+    delay.checked_sub(elapsed).filter(|left| !left.is_zero())
+}
+
+fn lockout(failed: &FailedLogins) -> Option<Duration> {
+    if failed.count >= LOCKOUT_THRESHOLD {
+        remaining(failed, WINDOW)
+    } else {
+        None
+    }
+}
+
+/// Whether `username` logging in from `ip` has to wait.
+pub async fn check(
+    store: &dyn SessionStore,
+    username: &str,
+    ip: Option<IpAddr>,
+) -> Result<Option<LoginBlock>> {
+    let by_user = store.failed_logins(&user_key(username)).await?;
+    if let Some(left) = by_user.as_ref().and_then(lockout) {
+        return Ok(Some(LoginBlock::Locked(left)));
+    }
+
+    let by_ip = match ip {
+        Some(ip) => store.failed_logins(&ip_key(ip)).await?,
+        None => None,
+    };
+    Ok([by_user, by_ip]
+        .iter()
+        .flatten()
+        .filter_map(|failed| remaining(failed, backoff(failed.count)))
+        .max()
+        .map(LoginBlock::Backoff))
+}
+
+/// Counts a failed login, returning the failures of `username` so far.
+pub async fn record_failure(
+    store: &dyn SessionStore,
+    username: &str,
+    ip: Option<IpAddr>,
+) -> Result<u32> {
+    if let Some(ip) = ip {
+        store.record_failed_login(&ip_key(ip), WINDOW).await?;
+    }
+    Ok(store
+        .record_failed_login(&user_key(username), WINDOW)
+        .await?
+        .count)
+}
+
+/// Forgets the failures of `username` and `ip` after a successful login.
+pub async fn record_success(
+    store: &dyn SessionStore,
+    username: &str,
+    ip: Option<IpAddr>,
+) -> Result<()> {
+    store.clear_failed_logins(&user_key(username)).await?;
+    match ip {
+        Some(ip) => store.clear_failed_logins(&ip_key(ip)).await,
+        None => Ok(()),
+    }
+}
+
+pub async fn is_locked(store: &dyn SessionStore, username: &str) -> Result<bool> {
+    Ok(store
+        .failed_logins(&user_key(username))
+        .await?
+        .as_ref()
+        .and_then(lockout)
+        .is_some())
+}
+
+/// Lifts the lockout of `username`, for admins.
+pub async fn unlock(store: &dyn SessionStore, username: &str) -> Result<()> {
+    store.clear_failed_logins(&user_key(username)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::MemoryStore;
+
+    const IP: Option<IpAddr> = Some(IpAddr::V4(std::net::Ipv4Addr::LOCALHOST));
+
+    fn failed(count: u32, seconds_ago: i64) -> FailedLogins {
+        FailedLogins {
+            count,
+            last_failed_at: Utc::now() - chrono::Duration::seconds(seconds_ago),
+        }
+    }
+
+    #[test]
+    fn backoff_doubles_after_the_free_attempts() {
+        let delays: Vec<u64> = (0..=12).map(|count| backoff(count).as_secs()).collect();
+        assert_eq!(delays, [0, 0, 0, 0, 1, 2, 4, 8, 16, 32, 64, 128, 256]);
+        assert_eq!(backoff(13), MAX_BACKOFF);
+        assert_eq!(backoff(u32::MAX), MAX_BACKOFF);
+    }
+
+    #[test]
+    fn backoff_runs_from_the_last_failure() {
+        assert!(remaining(&failed(5, 0), backoff(5)).is_some());
+        assert_eq!(remaining(&failed(5, 2), backoff(5)), None);
+        assert_eq!(remaining(&failed(3, 0), backoff(3)), None);
+    }
+
+    #[test]
+    fn lockout_starts_at_the_threshold_and_expires() {
+        assert_eq!(lockout(&failed(LOCKOUT_THRESHOLD - 1, 0)), None);
+        let left = lockout(&failed(LOCKOUT_THRESHOLD, 60)).unwrap();
+        assert!(left <= WINDOW - Duration::from_secs(60));
+        assert!(left > WINDOW - Duration::from_secs(70));
+        assert_eq!(lockout(&failed(LOCKOUT_THRESHOLD, 15 * 60)), None);
+    }
+
+    #[tokio::test]
+    async fn failures_lead_to_backoff_then_lockout() {
+        let store = MemoryStore::new(WINDOW);
+        for _ in 0..FREE_ATTEMPTS {
+            record_failure(&store, "admin", IP).await.unwrap();
+        }
+        assert_eq!(check(&store, "admin", IP).await.unwrap(), None);
+
+        let count = record_failure(&store, "Admin ", IP).await.unwrap();
+        assert_eq!(count, FREE_ATTEMPTS + 1);
+        assert!(matches!(
+            check(&store, "admin", IP).await.unwrap(),
+            Some(LoginBlock::Backoff(_))
+        ));
+        // The address is throttled for other usernames too
+        assert!(matches!(
+            check(&store, "vet", IP).await.unwrap(),
+            Some(LoginBlock::Backoff(_))
+        ));
+
+        while record_failure(&store, "admin", IP).await.unwrap() < LOCKOUT_THRESHOLD {}
+        assert!(is_locked(&store, "admin").await.unwrap());
+        assert!(matches!(
+            check(&store, "admin", IP).await.unwrap(),
+            Some(LoginBlock::Locked(_))
+        ));
+
+        unlock(&store, "admin").await.unwrap();
+        assert!(!is_locked(&store, "admin").await.unwrap());
+        record_success(&store, "admin", IP).await.unwrap();
+        assert_eq!(check(&store, "admin", IP).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn unknown_addresses_share_no_counter() {
+        let store = MemoryStore::new(WINDOW);
+        for _ in 0..LOCKOUT_THRESHOLD {
+            record_failure(&store, "admin", None).await.unwrap();
+        }
+        assert!(is_locked(&store, "admin").await.unwrap());
+        assert_eq!(check(&store, "vet", None).await.unwrap(), None);
+        assert_eq!(check(&store, "vet", IP).await.unwrap(), None);
+    }
+}
@@ -1 +1,11 @@
+pub mod api_tokens;
+pub mod appointments;
+pub mod audit;
+pub mod calendar;
+pub mod deletion;
+pub mod lockout;
+pub mod permissions;
+pub mod sessions;
+pub mod two_factor;
 pub mod users;
+pub mod validation;
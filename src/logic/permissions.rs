@@ -0,0 +1,226 @@
+use crate::db::models::user::{Role, User};
+use crate::AppError;
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::request::Parts,
+    response::{IntoResponse, Response},
+};
+use serde_json::Value;
+use std::{collections::HashMap, marker::PhantomData, str::FromStr};
+
+/// Actions guarded by the per-route permission layer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Permission {
+    View,
+    EditPets,
+    DeletePets,
+    EditVisits,
+    EditAppointments,
+    ManageVets,
+    ManageUsers,
+    ViewAuditLog,
+}
+
+impl FromStr for Permission {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "view" => Ok(Permission::View),
+            "edit_pets" => Ok(Permission::EditPets),
+            "delete_pets" => Ok(Permission::DeletePets),
+            "edit_visits" => Ok(Permission::EditVisits),
+            "edit_appointments" => Ok(Permission::EditAppointments),
+            "manage_vets" => Ok(Permission::ManageVets),
+            "manage_users" => Ok(Permission::ManageUsers),
+            "view_audit_log" => Ok(Permission::ViewAuditLog),
+            _ => Err(anyhow::anyhow!("Unknown permission: {s}")),
+        }
+    }
+}
+
+impl Role {
+    pub fn can(&self, permission: Permission) -> bool {
+        use Permission::*;
+
+        match self {
+            Role::Admin => true,
+            Role::Vet => matches!(permission, View | EditPets | EditVisits | EditAppointments),
+            Role::Receptionist => matches!(
+                permission,
+                View | EditPets | DeletePets | EditVisits | EditAppointments
+            ),
+            Role::ReadOnly => permission == View,
+        }
+    }
+}
+
+/// Ties a marker type to the [`Permission`] it requires, see [`Authorized`].
+pub trait RequiredPermission {
+    const PERMISSION: Permission;
+}
+
+macro_rules! required_permissions {
+    ($($name:ident),* $(,)?) => {
+        $(
+            pub struct $name;
+
+            impl RequiredPermission for $name {
+                const PERMISSION: Permission = Permission::$name;
+            }
+        )*
+    };
+}
+
+/// Marker types usable as `Authorized<P>`, one per [`Permission`].
+pub mod require {
+    use super::{Permission, RequiredPermission};
+
+    required_permissions!(
+        View,
+        EditPets,
+        DeletePets,
+        EditVisits,
+        EditAppointments,
+        ManageVets,
+        ManageUsers,
+        ViewAuditLog,
+    );
+}
+
+/// Extractor that resolves the session [`User`] and rejects the request with
+/// `403 Forbidden` when their role lacks `P::PERMISSION`. Meant to be used as
+/// `route_layer(from_extractor::<Authorized<require::X>>())`, the user is then
+/// left in the request extensions so the handler doesn't load it again.
+pub struct Authorized<P>(PhantomData<P>);
+
+pub enum AuthorizationRejection {
+    Unauthenticated(Response),
+    Forbidden,
+}
+
+impl IntoResponse for AuthorizationRejection {
+    fn into_response(self) -> Response {
+        match self {
+            AuthorizationRejection::Unauthenticated(response) => response,
+            AuthorizationRejection::Forbidden => AppError::Forbidden.into_response(),
+        }
+    }
+}
+
+#[async_trait]
+impl<S, P> FromRequestParts<S> for Authorized<P>
+where
+    S: Send + Sync,
+    P: RequiredPermission,
+{
+    type Rejection = AuthorizationRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let user = User::from_request_parts(parts, state)
+            .await
+            .map_err(|rejection| {
+                AuthorizationRejection::Unauthenticated(rejection.into_response())
+            })?;
+
+        if user.role.can(P::PERMISSION) {
+            Ok(Authorized(PhantomData))
+        } else {
+            tracing::warn!(
+                "User {} with role {} denied {:?}",
+                user.username,
+                user.role,
+                P::PERMISSION
+            );
+            Err(AuthorizationRejection::Forbidden)
+        }
+    }
+}
+
+/// Tera function `can(action="edit_pets")`, used to hide controls the user cannot use.
+pub struct Can {
+    pub role: Option<Role>,
+}
+
+impl tera::Function for Can {
+    fn call(&self, args: &HashMap<String, Value>) -> tera::Result<Value> {
+        let action = args
+            .get("action")
+            .and_then(Value::as_str)
+            .ok_or_else(|| tera::Error::msg("can() requires a string `action` argument"))?;
+        let permission =
+            Permission::from_str(action).map_err(|e| tera::Error::msg(e.to_string()))?;
+
+        Ok(Value::Bool(
+            self.role.is_some_and(|role| role.can(permission)),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tera::Function;
+
+    const ALL: [Permission; 8] = [
+        Permission::View,
+        Permission::EditPets,
+        Permission::DeletePets,
+        Permission::EditVisits,
+        Permission::EditAppointments,
+        Permission::ManageVets,
+        Permission::ManageUsers,
+        Permission::ViewAuditLog,
+    ];
+
+    fn allowed(role: Role) -> Vec<Permission> {
+        ALL.into_iter().filter(|p| role.can(*p)).collect()
+    }
+
+    #[test]
+    fn role_matrix() {
+        use Permission::*;
+
+        assert_eq!(allowed(Role::Admin), ALL);
+        assert_eq!(
+            allowed(Role::Vet),
+            [View, EditPets, EditVisits, EditAppointments]
+        );
+        assert_eq!(
+            allowed(Role::Receptionist),
+            [View, EditPets, DeletePets, EditVisits, EditAppointments]
+        );
+        assert_eq!(allowed(Role::ReadOnly), [View]);
+    }
+
+    #[test]
+    fn permissions_parse_from_template_names() {
+        for (name, permission) in [
+            ("view", Permission::View),
+            ("edit_pets", Permission::EditPets),
+            ("delete_pets", Permission::DeletePets),
+            ("edit_visits", Permission::EditVisits),
+            ("edit_appointments", Permission::EditAppointments),
+            ("manage_vets", Permission::ManageVets),
+            ("manage_users", Permission::ManageUsers),
+            ("view_audit_log", Permission::ViewAuditLog),
+        ] {
+            assert_eq!(Permission::from_str(name).unwrap(), permission);
+        }
+        assert!(Permission::from_str("delete_everything").is_err());
+    }
+
+    #[test]
+    fn can_function_without_role_denies() {
+        let args = HashMap::from([("action".to_string(), Value::from("view"))]);
+        let can = Can { role: None };
+        assert_eq!(can.call(&args).unwrap(), Value::Bool(false));
+
+        let can = Can {
+            role: Some(Role::ReadOnly),
+        };
+        assert_eq!(can.call(&args).unwrap(), Value::Bool(true));
+        assert!(can.call(&HashMap::new()).is_err());
+    }
+}
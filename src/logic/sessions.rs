@@ -0,0 +1,79 @@
+//! Listing and revoking the login sessions of a user.
+
+use crate::{
+    logic::api_tokens::hash_token,
+    session::{SessionInfo, SessionStore},
+};
+use anyhow::Result;
+use chrono::Utc;
+use serde::Serialize;
+use std::{cmp::Reverse, net::IpAddr};
+
+/// Longer user agents are cut, they are only shown to tell sessions apart.
+const MAX_USER_AGENT_CHARS: usize = 255;
+
+/// A session as shown on the sessions page. The key itself is never sent to
+/// the browser outside its own cookie, pages use the `id` derived from it.
+#[derive(Debug, Serialize)]
+pub struct ActiveSession {
+    pub id: String,
+    pub current: bool,
+    #[serde(flatten)]
+    pub info: SessionInfo,
+}
+
+/// Details of a session started now by `user_id` from `ip`.
+pub fn new_info(user_id: i32, ip: Option<IpAddr>, user_agent: Option<&str>) -> SessionInfo {
+    let now = Utc::now();
+    SessionInfo {
+        user_id,
+        created_at: now,
+        last_seen_at: now,
+        ip: ip.map(|ip| ip.to_string()),
+        user_agent: user_agent.map(|ua| ua.chars().take(MAX_USER_AGENT_CHARS).collect()),
+    }
+}
+
+fn public_id(key: &str) -> String {
+    hash_token(key)[..16].to_string()
+}
+
+/// The sessions of `user_id`, most recently used first. `current_key` marks
+/// the session making the request.
+pub async fn list(
+    store: &dyn SessionStore,
+    user_id: i32,
+    current_key: Option<&str>,
+) -> Result<Vec<ActiveSession>> {
+    let mut sessions: Vec<ActiveSession> = store
+        .user_sessions(user_id)
+        .await?
+        .into_iter()
+        .map(|(key, info)| ActiveSession {
+            id: public_id(&key),
+            current: current_key == Some(key.as_str()),
+            info,
+        })
+        .collect();
+    sessions.sort_by_key(|session| Reverse(session.info.last_seen_at));
+    Ok(sessions)
+}
+
+/// Ends the session of `user_id` with the given id, returning whether it
+/// existed.
+pub async fn revoke(store: &dyn SessionStore, user_id: i32, id: &str) -> Result<bool> {
+    let key = store
+        .user_sessions(user_id)
+        .await?
+        .into_iter()
+        .map(|(key, _)| key)
+        .find(|key| public_id(key) == id);
+
+    match key {
+        Some(key) => {
+            store.remove(&key).await?;
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
@@ -0,0 +1,374 @@
+//! Optional second login factor: RFC 6238 time-based one-time passwords from
+//! an authenticator app, plus single-use recovery codes for a lost device.
+
+use crate::{
+    db::models::{
+        recovery_code::{NewRecoveryCode, RecoveryCode},
+        user_totp::UserTotp,
+    },
+    errors::UserError,
+    logic::{
+        api_tokens::hash_token,
+        audit::{self, Actor, Entity},
+    },
+};
+use anyhow::{bail, Result};
+use chrono::Utc;
+use diesel::SqliteConnection;
+use hmac::{Hmac, Mac};
+use qrcode::{render::svg, QrCode};
+use rand::{distributions::Alphanumeric, Rng};
+use serde_json::json;
+use sha1::Sha1;
+use std::time::Duration;
+use subtle::ConstantTimeEq;
+
+const ISSUER: &str = "PetClinic";
+const SECRET_BYTES: usize = 20;
+const DIGITS: u32 = 6;
+const STEP_SECONDS: i64 = 30;
+/// Codes of the neighbouring time steps are accepted for clock drift.
+const ALLOWED_DRIFT: i64 = 1;
+const RECOVERY_CODES: usize = 10;
+
+/// How long the code can be entered after the password was accepted.
+pub const PENDING_LOGIN_TTL: Duration = Duration::from_secs(5 * 60);
+
+const BASE32: base32::Alphabet = base32::Alphabet::RFC4648 { padding: false };
+
+fn current_step() -> i64 {
+    Utc::now().timestamp() / STEP_SECONDS
+}
+
+/// HOTP value (RFC 4226) of `secret` for the counter `step`.
+fn code_at(secret: &[u8], step: i64) -> Result<u32> {
+    let mut mac = Hmac::<Sha1>::new_from_slice(secret)?;
+    mac.update(&step.to_be_bytes());
+    let digest = mac.finalize().into_bytes();
+
+    let offset = (digest[digest.len() - 1] & 0x0f) as usize;
+    let value = u32::from_be_bytes([
+        digest[offset] & 0x7f,
+        digest[offset + 1],
+        digest[offset + 2],
+        digest[offset + 3],
+    ]);
+    Ok(value % 10u32.pow(DIGITS))
+}
+
+/// The time step `code` was generated for, if it is valid now and newer than
+/// `last_used_step`.
+fn matching_step(secret: &str, code: &str, last_used_step: Option<i64>) -> Result<Option<i64>> {
+    let Some(secret) = base32::decode(BASE32, secret) else {
+        bail!("Stored TOTP secret is not valid base32");
+    };
+    if code.len() != DIGITS as usize || !code.bytes().all(|b| b.is_ascii_digit()) {
+        return Ok(None);
+    }
+
+    let now = current_step();
+    for step in now - ALLOWED_DRIFT..=now + ALLOWED_DRIFT {
+        if last_used_step.is_some_and(|last| step <= last) {
+            continue;
+        }
+        let expected = format!(
+            "{:0width$}",
+            code_at(&secret, step)?,
+            width = DIGITS as usize
+        );
+        if bool::from(expected.as_bytes().ct_eq(code.as_bytes())) {
+            return Ok(Some(step));
+        }
+    }
+    Ok(None)
+}
+
+/// Recovery codes are compared case-insensitively and without the dash.
+fn normalize_recovery_code(code: &str) -> String {
+    code.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .collect::<String>()
+        .to_lowercase()
+}
+
+fn new_recovery_codes(conn: &mut SqliteConnection, user_id: i32) -> Result<Vec<String>> {
+    let codes: Vec<String> = (0..RECOVERY_CODES)
+        .map(|_| {
+            let code: String = rand::thread_rng()
+                .sample_iter(&Alphanumeric)
+                .take(10)
+                .map(char::from)
+                .collect::<String>()
+                .to_lowercase();
+            format!("{}-{}", &code[..5], &code[5..])
+        })
+        .collect();
+
+    RecoveryCode::delete_by_user_id(conn, user_id)?;
+    let new_codes: Vec<NewRecoveryCode> = codes
+        .iter()
+        .map(|code| NewRecoveryCode {
+            user_id,
+            code_hash: hash_token(&normalize_recovery_code(code)),
+        })
+        .collect();
+    NewRecoveryCode::save_all(&new_codes, conn)?;
+
+    Ok(codes)
+}
+
+pub fn is_enabled(conn: &mut SqliteConnection, user_id: i32) -> Result<bool> {
+    Ok(UserTotp::select_by_user_id(conn, user_id)?.is_some_and(|totp| totp.enabled))
+}
+
+/// Starts an enrollment with a new secret, returning it base32 encoded. Logins
+/// are unaffected until the enrollment is confirmed with [`confirm_enrollment`].
+pub fn start_enrollment(conn: &mut SqliteConnection, user_id: i32) -> Result<String> {
+    if is_enabled(conn, user_id)? {
+        return Err(UserError::Conflict(
+            "Two-factor authentication is already enabled".to_string(),
+        )
+        .into());
+    }
+
+    let secret: [u8; SECRET_BYTES] = rand::thread_rng().gen();
+    let secret = base32::encode(BASE32, &secret);
+    UserTotp {
+        user_id,
+        secret: secret.clone(),
+        enabled: false,
+        last_used_step: None,
+        created_at: Utc::now().naive_utc(),
+    }
+    .save(conn)?;
+
+    Ok(secret)
+}
+
+/// Enables two-factor login once the user proves their app generates the
+/// right codes. Returns the recovery codes, which are only shown this once.
+pub fn confirm_enrollment(
+    conn: &mut SqliteConnection,
+    actor: &Actor,
+    user_id: i32,
+    code: &str,
+) -> Result<Option<Vec<String>>> {
+    conn.immediate_transaction(|conn| {
+        let Some(totp) = UserTotp::select_by_user_id(conn, user_id)? else {
+            return Err(UserError::Validation(
+                "Two-factor authentication setup was not started".to_string(),
+            )
+            .into());
+        };
+        if totp.enabled {
+            return Err(UserError::Conflict(
+                "Two-factor authentication is already enabled".to_string(),
+            )
+            .into());
+        }
+        let Some(step) = matching_step(&totp.secret, code.trim(), None)? else {
+            return Ok(None);
+        };
+
+        UserTotp::enable(conn, user_id, step)?;
+        let codes = new_recovery_codes(conn, user_id)?;
+        audit::created(
+            conn,
+            actor,
+            Entity::TwoFactor,
+            user_id,
+            &json!({ "enabled": true }),
+        )?;
+        Ok(Some(codes))
+    })
+}
+
+/// Checks a TOTP code or an unused recovery code of `user_id`. Either can
+/// only be used once.
+pub fn verify(conn: &mut SqliteConnection, user_id: i32, code: &str) -> Result<bool> {
+    conn.immediate_transaction(|conn| {
+        let Some(totp) = UserTotp::select_by_user_id(conn, user_id)?.filter(|t| t.enabled) else {
+            return Ok(false);
+        };
+
+        let code = code.trim();
+        if let Some(step) = matching_step(&totp.secret, code, totp.last_used_step)? {
+            UserTotp::set_last_used_step(conn, user_id, step)?;
+            return Ok(true);
+        }
+
+        let code_hash = hash_token(&normalize_recovery_code(code));
+        match RecoveryCode::select_unused_by_hash(conn, user_id, &code_hash)? {
+            Some(recovery_code) => {
+                let now = Utc::now().naive_utc();
+                Ok(RecoveryCode::mark_used(conn, recovery_code.id, now)? == 1)
+            }
+            None => Ok(false),
+        }
+    })
+}
+
+/// Replaces all recovery codes of `user_id` with new ones.
+pub fn regenerate_recovery_codes(
+    conn: &mut SqliteConnection,
+    actor: &Actor,
+    user_id: i32,
+) -> Result<Vec<String>> {
+    conn.immediate_transaction(|conn| {
+        let codes = new_recovery_codes(conn, user_id)?;
+        audit::secret_changed(conn, actor, Entity::TwoFactor, user_id, "recovery_codes")?;
+        Ok(codes)
+    })
+}
+
+pub fn remaining_recovery_codes(conn: &mut SqliteConnection, user_id: i32) -> Result<i64> {
+    RecoveryCode::count_unused(conn, user_id)
+}
+
+/// Turns two-factor login off, forgetting the secret and recovery codes.
+pub fn disable(conn: &mut SqliteConnection, actor: &Actor, user_id: i32) -> Result<()> {
+    conn.immediate_transaction(|conn| {
+        let was_enabled = is_enabled(conn, user_id)?;
+        UserTotp::delete_by_user_id(conn, user_id)?;
+        RecoveryCode::delete_by_user_id(conn, user_id)?;
+        if was_enabled {
+            audit::deleted(
+                conn,
+                actor,
+                Entity::TwoFactor,
+                user_id,
+                &json!({ "enabled": true }),
+            )?;
+        }
+        Ok(())
+    })
+}
+
+/// `otpauth://` URI understood by authenticator apps.
+pub fn provisioning_uri(username: &str, secret: &str) -> String {
+    let label: String = format!("{ISSUER}:{username}")
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b':' => {
+                char::from(b).to_string()
+            }
+            _ => format!("%{b:02X}"),
+        })
+        .collect();
+    format!(
+        "otpauth://totp/{label}?secret={secret}&issuer={ISSUER}&algorithm=SHA1&digits={DIGITS}&period={STEP_SECONDS}"
+    )
+}
+
+/// The provisioning URI as a QR code, rendered as an inline SVG image.
+pub fn qr_code_svg(uri: &str) -> Result<String> {
+    Ok(QrCode::new(uri.as_bytes())?
+        .render::<svg::Color>()
+        .min_dimensions(200, 200)
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use diesel::Connection;
+
+    /// The SHA-1 secret of the RFC 6238 test vectors.
+    const RFC_SECRET: &[u8] = b"12345678901234567890";
+
+    fn code_now(secret: &str, offset: i64) -> String {
+        let secret = base32::decode(BASE32, secret).unwrap();
+        format!("{:06}", code_at(&secret, current_step() + offset).unwrap())
+    }
+
+    /// A migrated in-memory database, which comes with the `admin` user.
+    fn connection() -> SqliteConnection {
+        let mut conn = SqliteConnection::establish(":memory:").unwrap();
+        crate::db::migrations::run_pending(&mut conn).unwrap();
+        conn
+    }
+
+    #[test]
+    fn rfc6238_vectors() {
+        // Appendix B, truncated to our six digits
+        for (time, code) in [
+            (59, 287082),
+            (1111111109, 81804),
+            (1111111111, 50471),
+            (1234567890, 5924),
+            (2000000000, 279037),
+            (20000000000, 353130),
+        ] {
+            assert_eq!(code_at(RFC_SECRET, time / STEP_SECONDS).unwrap(), code);
+        }
+    }
+
+    #[test]
+    fn codes_are_accepted_within_the_drift_once() {
+        let secret = base32::encode(BASE32, RFC_SECRET);
+        for offset in -ALLOWED_DRIFT..=ALLOWED_DRIFT {
+            let code = code_now(&secret, offset);
+            let step = matching_step(&secret, &code, None).unwrap().unwrap();
+            assert_eq!(matching_step(&secret, &code, Some(step)).unwrap(), None);
+        }
+        let stale = code_now(&secret, -ALLOWED_DRIFT - 3);
+        assert_eq!(matching_step(&secret, &stale, None).unwrap(), None);
+    }
+
+    #[test]
+    fn malformed_codes_are_rejected() {
+        let secret = base32::encode(BASE32, RFC_SECRET);
+        let code = code_now(&secret, 0);
+        for code in [&code[1..], &format!("{code}0"), "abcdef", ""] {
+            assert_eq!(matching_step(&secret, code, None).unwrap(), None);
+        }
+        assert!(matching_step("not base32!", &code, None).is_err());
+    }
+
+    #[test]
+    fn recovery_codes_work_once() {
+        let conn = &mut connection();
+        let actor = Actor::cli();
+        let secret = start_enrollment(conn, 1).unwrap();
+        let codes = confirm_enrollment(conn, &actor, 1, &code_now(&secret, 0))
+            .unwrap()
+            .unwrap();
+        assert_eq!(codes.len(), RECOVERY_CODES);
+        assert!(is_enabled(conn, 1).unwrap());
+
+        assert!(verify(conn, 1, &codes[0]).unwrap());
+        assert!(!verify(conn, 1, &codes[0]).unwrap());
+        // Typed in upper case and without the dash
+        let typed = codes[1].replace('-', "").to_uppercase();
+        assert!(verify(conn, 1, &typed).unwrap());
+        assert!(!verify(conn, 1, &codes[1]).unwrap());
+        assert_eq!(
+            remaining_recovery_codes(conn, 1).unwrap(),
+            RECOVERY_CODES as i64 - 2
+        );
+
+        regenerate_recovery_codes(conn, &actor, 1).unwrap();
+        assert!(!verify(conn, 1, &codes[2]).unwrap());
+        assert_eq!(
+            remaining_recovery_codes(conn, 1).unwrap(),
+            RECOVERY_CODES as i64
+        );
+    }
+
+    #[test]
+    fn totp_codes_work_once() {
+        let conn = &mut connection();
+        let secret = start_enrollment(conn, 1).unwrap();
+        assert!(!verify(conn, 1, &code_now(&secret, 0)).unwrap());
+
+        let confirmed = code_now(&secret, 0);
+        confirm_enrollment(conn, &Actor::cli(), 1, &confirmed)
+            .unwrap()
+            .unwrap();
+        // The code used to confirm the enrollment is spent as well
+        assert!(!verify(conn, 1, &confirmed).unwrap());
+        let next = code_now(&secret, 1);
+        assert!(verify(conn, 1, &next).unwrap());
+        assert!(!verify(conn, 1, &next).unwrap());
+    }
+}
@@ -1,47 +1,240 @@
-use crate::{context::Context, db::models::user::User, AppError};
+use crate::{
+    context::Context,
+    db::models::user::{NewUser, Role, User},
+    errors::UserError,
+    logic::audit::{self, Actor, Entity},
+    AppError,
+};
+use anyhow::bail;
 use anyhow::Result;
+use argon2::{
+    password_hash::{rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+    Argon2,
+};
+use diesel::SqliteConnection;
 use rand::{distributions::Alphanumeric, Rng};
 use sha1::{Digest, Sha1};
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
+use subtle::ConstantTimeEq;
+
+pub const MIN_PASSWORD_LENGTH: usize = 8;
+
+/// Outcome of checking a password against the hash stored for a user.
+#[derive(Debug, PartialEq, Eq)]
+enum Verification {
+    Valid,
+    /// The password matched a legacy unsalted SHA-1 hash and must be rehashed.
+    ValidLegacy,
+    Invalid,
+}
+
+/// Hashes `password` with Argon2id and a fresh random salt, returning a PHC string.
+pub fn hash_password(password: &str) -> Result<String> {
+    let salt = SaltString::generate(&mut OsRng);
+    let hash = Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map_err(|e| anyhow::Error::msg(e.to_string()))?;
+
+    Ok(hash.to_string())
+}
+
+fn check_new_password(password: &str) -> Result<()> {
+    if password.chars().count() < MIN_PASSWORD_LENGTH {
+        return Err(UserError::Validation(format!(
+            "Password must be at least {MIN_PASSWORD_LENGTH} characters long"
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+/// Creates a new enabled user with a freshly hashed password.
+pub fn create_user(
+    conn: &mut SqliteConnection,
+    actor: &Actor,
+    username: &str,
+    password: &str,
+    role: Role,
+) -> Result<()> {
+    let username = username.trim();
+    if username.is_empty() {
+        return Err(UserError::Validation("Username cannot be empty".to_string()).into());
+    }
+    check_new_password(password)?;
+    let password = hash_password(password)?;
+
+    conn.immediate_transaction(|conn| {
+        NewUser {
+            username: username.to_string(),
+            password,
+            role,
+        }
+        .save(conn)?;
+        let user = User::select_by_name(conn, username)?;
+        audit::created(conn, actor, Entity::User, user.id, &user)
+    })
+}
+
+/// Replaces the password of user `id` with a fresh hash of `password`.
+pub fn reset_password(
+    conn: &mut SqliteConnection,
+    actor: &Actor,
+    id: i32,
+    password: &str,
+) -> Result<()> {
+    check_new_password(password)?;
+    let password_hash = hash_password(password)?;
+
+    conn.immediate_transaction(|conn| {
+        if User::update_password(conn, id, &password_hash)? == 0 {
+            bail!("User {id} not found");
+        }
+        audit::secret_changed(conn, actor, Entity::User, id, "password")
+    })
+}
+
+pub fn set_role(conn: &mut SqliteConnection, actor: &Actor, id: i32, role: Role) -> Result<()> {
+    update_user(conn, actor, id, |conn| User::set_role(conn, id, role))
+}
+
+pub fn set_disabled(
+    conn: &mut SqliteConnection,
+    actor: &Actor,
+    id: i32,
+    disabled: bool,
+) -> Result<()> {
+    update_user(conn, actor, id, |conn| {
+        User::set_disabled(conn, id, disabled)
+    })
+}
+
+/// Runs `update` on user `id` and logs what it changed.
+fn update_user(
+    conn: &mut SqliteConnection,
+    actor: &Actor,
+    id: i32,
+    update: impl FnOnce(&mut SqliteConnection) -> Result<usize>,
+) -> Result<()> {
+    conn.immediate_transaction(|conn| {
+        let Some(before) = User::select_by_id(conn, id)? else {
+            bail!("User {id} not found");
+        };
+        update(conn)?;
+        let Some(after) = User::select_by_id(conn, id)? else {
+            bail!("User {id} not found");
+        };
+        audit::updated(conn, actor, Entity::User, id, &before, &after)
+    })
+}
+
+fn is_legacy_hash(stored: &str) -> bool {
+    stored.len() == 40 && stored.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+fn verify_password(password: &str, stored: &str) -> Verification {
+    if is_legacy_hash(stored) {
+        let mut hasher = Sha1::new();
+        hasher.update(password);
+        let encrypted: String = format!("{:x}", hasher.finalize());
+        return if bool::from(
+            encrypted
+                .as_bytes()
+                .ct_eq(stored.to_ascii_lowercase().as_bytes()),
+        ) {
+            Verification::ValidLegacy
+        } else {
+            Verification::Invalid
+        };
+    }
+
+    match PasswordHash::new(stored) {
+        // `verify_password` compares the computed output in constant time
+        Ok(hash) => match Argon2::default().verify_password(password.as_bytes(), &hash) {
+            Ok(()) => Verification::Valid,
+            Err(_) => Verification::Invalid,
+        },
+        Err(e) => {
+            tracing::error!("Stored password hash cannot be parsed: {e}");
+            Verification::Invalid
+        }
+    }
+}
 
 pub async fn authenticate(
     username: &str,
     password: &str,
     ctx: &Arc<Context>,
 ) -> Result<Option<User>> {
-    let db_conn = ctx.db_connection_pool.get().await?;
+    let db_conn = ctx.db_connection().await?;
     let u = username.to_owned();
     let user = db_conn
         .interact(move |conn| User::select_by_name(conn, &u))
         .await
-        .map_err(|e| AppError {
-            inner: anyhow::Error::msg(e.to_string()),
-        });
-
-    match user {
-        Ok(Ok(user)) => {
-            // Password verification
-            let mut hasher = Sha1::new();
-            hasher.update(password);
-            let encrypted: String = format!("{:x}", hasher.finalize());
-            if encrypted == user.password || user.password.is_empty() {
-                Ok(Some(user))
-            } else {
-                tracing::error!("Wrong password for user: {username}");
-                Ok(None)
-            }
-        }
+        .map_err(|e| AppError::Internal(anyhow::Error::msg(e.to_string())));
+
+    let user = match user {
+        Ok(Ok(user)) => Some(user),
         Ok(Err(e)) => {
-            tracing::error!("User not found: {username}, '{e:?}'");
-            Ok(None)
+            tracing::warn!("User not found: {username}, '{e:?}'");
+            None
         }
         Err(e) => {
             tracing::error!("User not found: {username}, '{e:?}'");
+            None
+        }
+    };
+
+    // Unknown users are checked against a dummy hash, so they take as long
+    // as a wrong password and don't give away which usernames exist. Hashing
+    // is slow on purpose and runs off the async workers.
+    let stored = user.as_ref().map(|user| user.password.clone());
+    let pw = password.to_owned();
+    let verification = tokio::task::spawn_blocking(move || match stored {
+        Some(stored) => verify_password(&pw, &stored),
+        None => {
+            verify_password(&pw, dummy_hash());
+            Verification::Invalid
+        }
+    })
+    .await?;
+
+    let Some(user) = user else {
+        return Ok(None);
+    };
+    if user.disabled {
+        tracing::warn!("Login attempt for disabled user: {username}");
+        return Ok(None);
+    }
+
+    match verification {
+        Verification::Valid => Ok(Some(user)),
+        Verification::ValidLegacy => {
+            // Transparently migrate the legacy SHA-1 hash to Argon2id
+            let pw = password.to_owned();
+            let hash = tokio::task::spawn_blocking(move || hash_password(&pw)).await??;
+            let id = user.id;
+            let rehashed = db_conn
+                .interact(move |conn| User::update_password(conn, id, &hash))
+                .await
+                .map_err(|e| anyhow::Error::msg(e.to_string()))?;
+            if let Err(e) = rehashed {
+                tracing::error!("Failed to rehash password for user: {username}, '{e:?}'");
+            }
+            Ok(Some(user))
+        }
+        Verification::Invalid => {
+            tracing::warn!("Wrong password for user: {username}");
             Ok(None)
         }
     }
 }
 
+/// Hash of an empty password, see [`authenticate`].
+fn dummy_hash() -> &'static str {
+    static DUMMY_HASH: OnceLock<String> = OnceLock::new();
+    DUMMY_HASH.get_or_init(|| hash_password("").unwrap_or_default())
+}
+
 pub fn session_key() -> String {
     rand::thread_rng()
         .sample_iter(&Alphanumeric)
@@ -49,3 +242,41 @@ pub fn session_key() -> String {
         .map(char::from)
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_round_trip() {
+        let hash = hash_password("correct horse").unwrap();
+        assert!(hash.starts_with("$argon2id$"));
+        assert_eq!(verify_password("correct horse", &hash), Verification::Valid);
+        assert_eq!(verify_password("wrong horse", &hash), Verification::Invalid);
+        // Salted, so the same password never hashes the same twice
+        assert_ne!(hash, hash_password("correct horse").unwrap());
+    }
+
+    #[test]
+    fn legacy_sha1_hashes_are_accepted_for_rehashing() {
+        // SHA-1 of "admin"
+        let stored = "d033e22ae348aeb5660fc2140aec35850c4da997";
+        assert_eq!(verify_password("admin", stored), Verification::ValidLegacy);
+        assert_eq!(
+            verify_password("admin", &stored.to_ascii_uppercase()),
+            Verification::ValidLegacy
+        );
+        assert_eq!(verify_password("nimda", stored), Verification::Invalid);
+    }
+
+    #[test]
+    fn unparsable_hashes_never_match() {
+        assert_eq!(verify_password("", ""), Verification::Invalid);
+        assert_eq!(verify_password("admin", "admin"), Verification::Invalid);
+    }
+
+    #[test]
+    fn dummy_hash_is_a_valid_hash() {
+        assert_eq!(verify_password("", dummy_hash()), Verification::Valid);
+    }
+}
@@ -0,0 +1,355 @@
+//! Checks of submitted pets, vets, owners and visits, shared by the HTML forms and the
+//! JSON API so both accept exactly the same data.
+//!
+//! Problems are reported per field, keyed by the input's field name, for the
+//! forms to show next to the offending input.
+
+use crate::db::models::{owner::Owner, pet, vet::Vet};
+use anyhow::Result;
+use chrono::NaiveDate;
+use diesel::SqliteConnection;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+const MAX_NAME_LENGTH: usize = 100;
+const MAX_PET_AGE: i32 = 100;
+const MIN_PHONE_DIGITS: usize = 6;
+const MAX_PHONE_DIGITS: usize = 15;
+
+/// What is wrong with submitted values, by field. Raised as an error, so it
+/// can leave an `interact` closure and be told apart from other failures.
+#[derive(Debug, Default, Serialize)]
+pub struct FieldErrors(BTreeMap<String, String>);
+
+impl FieldErrors {
+    /// Records a problem with `field`, keeping the first one found.
+    pub fn add(&mut self, field: &str, message: impl Into<String>) {
+        self.0
+            .entry(field.to_string())
+            .or_insert_with(|| message.into());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Fails with the errors if there are any.
+    pub fn into_result(self) -> Result<()> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self.into())
+        }
+    }
+
+    /// Parses a form value, recording an error and returning the default if
+    /// it is not a `T`.
+    pub fn parse<T: FromStr + Default>(&mut self, field: &str, label: &str, value: &str) -> T {
+        let value = value.trim();
+        if value.is_empty() {
+            self.add(field, format!("{label} is required"));
+            return T::default();
+        }
+        value.parse().unwrap_or_else(|_| {
+            self.add(field, format!("{label} must be a whole number"));
+            T::default()
+        })
+    }
+
+    fn check_name(&mut self, field: &str, name: &str) {
+        let name = name.trim();
+        if name.is_empty() {
+            self.add(field, "Name is required");
+        } else if name.chars().count() > MAX_NAME_LENGTH {
+            self.add(
+                field,
+                format!("Name must be at most {MAX_NAME_LENGTH} characters"),
+            );
+        }
+    }
+}
+
+impl fmt::Display for FieldErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let messages: Vec<&str> = self.0.values().map(String::as_str).collect();
+        f.write_str(&messages.join(", "))
+    }
+}
+
+impl std::error::Error for FieldErrors {}
+
+#[derive(Deserialize)]
+pub struct PetInput {
+    pub name: String,
+    pub owner_id: i32,
+    pub age: i32,
+    pub pet_type: i32,
+    pub vet_id: Option<i32>,
+}
+
+impl PetInput {
+    /// Adds what is wrong with this pet to `errors`.
+    pub fn check(&self, conn: &mut SqliteConnection, errors: &mut FieldErrors) -> Result<()> {
+        errors.check_name("name", &self.name);
+        if !(0..=MAX_PET_AGE).contains(&self.age) {
+            errors.add("age", format!("Age must be between 0 and {MAX_PET_AGE}"));
+        }
+        let known_type = u32::try_from(self.pet_type)
+            .map(|pet_type| pet::types().contains_key(&pet_type))
+            .unwrap_or(false);
+        if !known_type {
+            errors.add("pet_type", "Unknown pet type");
+        }
+        if Owner::select_by_id(conn, self.owner_id)?.is_none() {
+            errors.add("owner_id", "Choose an existing owner");
+        }
+        if let Some(vet_id) = self.vet_id {
+            if Vet::select_by_id(conn, vet_id)?.is_none() {
+                errors.add("vet_id", "Choose an existing vet");
+            }
+        }
+        Ok(())
+    }
+
+    /// Fails with [`FieldErrors`] unless the pet can be saved.
+    pub fn validate(&self, conn: &mut SqliteConnection) -> Result<()> {
+        let mut errors = FieldErrors::default();
+        self.check(conn, &mut errors)?;
+        errors.into_result()
+    }
+}
+
+#[derive(Deserialize)]
+pub struct VisitInput {
+    pub vet_id: i32,
+    pub visit_date: NaiveDate,
+    pub notes: Option<String>,
+}
+
+impl VisitInput {
+    /// Fails with [`FieldErrors`] unless the visit can be saved. The pet comes
+    /// from the URL and is checked by the caller.
+    pub fn validate(&self, conn: &mut SqliteConnection) -> Result<()> {
+        let mut errors = FieldErrors::default();
+        if Vet::select_by_id(conn, self.vet_id)?.is_none() {
+            errors.add("vet_id", "Choose an existing vet");
+        }
+        errors.into_result()
+    }
+}
+
+#[derive(Deserialize)]
+pub struct VetInput {
+    pub name: String,
+}
+
+impl VetInput {
+    /// Fails with [`FieldErrors`] unless the vet can be saved.
+    pub fn validate(&self) -> Result<()> {
+        let mut errors = FieldErrors::default();
+        errors.check_name("name", &self.name);
+        errors.into_result()
+    }
+}
+
+#[derive(Deserialize)]
+pub struct OwnerInput {
+    pub name: String,
+    pub phone: String,
+    pub email: Option<String>,
+    pub address: Option<String>,
+}
+
+impl OwnerInput {
+    /// Fails with [`FieldErrors`] unless the owner can be saved.
+    pub fn validate(&self) -> Result<()> {
+        let mut errors = FieldErrors::default();
+        errors.check_name("name", &self.name);
+
+        // Digits, optionally grouped the way people write them down
+        let phone = self.phone.trim();
+        let digits = phone.chars().filter(char::is_ascii_digit).count();
+        if phone.is_empty() {
+            errors.add("phone", "Phone is required");
+        } else if !phone
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, ' ' | '+' | '-' | '(' | ')' | '.' | '/'))
+            || !(MIN_PHONE_DIGITS..=MAX_PHONE_DIGITS).contains(&digits)
+        {
+            errors.add(
+                "phone",
+                format!("Phone must be {MIN_PHONE_DIGITS} to {MAX_PHONE_DIGITS} digits, like +1 555-0100"),
+            );
+        }
+
+        if let Some(email) = self.email.as_deref().map(str::trim) {
+            if !email.is_empty() && !email.contains('@') {
+                errors.add("email", "Email must contain @");
+            }
+        }
+        errors.into_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use diesel::Connection;
+
+    /// Fields rejected by a failed validation, in order.
+    fn fields(result: Result<()>) -> Vec<String> {
+        match result {
+            Ok(()) => Vec::new(),
+            Err(e) => e
+                .downcast::<FieldErrors>()
+                .expect("validation fails with FieldErrors")
+                .0
+                .into_keys()
+                .collect(),
+        }
+    }
+
+    fn owner(name: &str, phone: &str, email: Option<&str>) -> OwnerInput {
+        OwnerInput {
+            name: name.to_string(),
+            phone: phone.to_string(),
+            email: email.map(str::to_string),
+            address: None,
+        }
+    }
+
+    fn pet(name: &str, age: i32, pet_type: i32, owner_id: i32, vet_id: Option<i32>) -> PetInput {
+        PetInput {
+            name: name.to_string(),
+            owner_id,
+            age,
+            pet_type,
+            vet_id,
+        }
+    }
+
+    #[test]
+    fn names_are_required_and_limited() {
+        let name = |name: &str| {
+            fields(
+                VetInput {
+                    name: name.to_string(),
+                }
+                .validate(),
+            )
+        };
+        assert!(name("Helen Leary").is_empty());
+        assert!(name(&"é".repeat(MAX_NAME_LENGTH)).is_empty());
+        assert_eq!(name(""), ["name"]);
+        assert_eq!(name("   "), ["name"]);
+        assert_eq!(name(&"a".repeat(MAX_NAME_LENGTH + 1)), ["name"]);
+    }
+
+    #[test]
+    fn phone_numbers() {
+        for phone in ["555-0100", "+1 (555) 010-0100", "030/1234567", " 555.0100 "] {
+            assert!(
+                fields(owner("Ann", phone, None).validate()).is_empty(),
+                "{phone}"
+            );
+        }
+        for phone in [
+            "",
+            "333",
+            "12345",
+            "call me",
+            "555-0100 ext 2",
+            &"1".repeat(16),
+        ] {
+            assert_eq!(
+                fields(owner("Ann", phone, None).validate()),
+                ["phone"],
+                "{phone}"
+            );
+        }
+    }
+
+    #[test]
+    fn emails_are_optional() {
+        assert!(fields(owner("Ann", "555-0100", None).validate()).is_empty());
+        assert!(fields(owner("Ann", "555-0100", Some(" ")).validate()).is_empty());
+        assert!(fields(owner("Ann", "555-0100", Some("ann@example.com")).validate()).is_empty());
+        assert_eq!(
+            fields(owner("Ann", "555-0100", Some("ann")).validate()),
+            ["email"]
+        );
+        assert_eq!(
+            fields(owner("", "1", Some("ann")).validate()),
+            ["email", "name", "phone"]
+        );
+    }
+
+    #[test]
+    fn parse_reports_missing_and_malformed_numbers() {
+        let mut errors = FieldErrors::default();
+        assert_eq!(errors.parse::<i32>("age", "Age", " 7 "), 7);
+        assert!(errors.is_empty());
+        assert_eq!(errors.parse::<i32>("age", "Age", ""), 0);
+        assert_eq!(errors.parse::<i32>("owner_id", "Owner", "two"), 0);
+        // Only the first problem of a field is kept
+        errors.add("age", "Age must be between 0 and 100");
+        assert_eq!(
+            errors.to_string(),
+            "Age is required, Owner must be a whole number"
+        );
+    }
+
+    /// A migrated in-memory database with the sample owners and vets.
+    fn connection() -> SqliteConnection {
+        let mut conn = SqliteConnection::establish(":memory:").unwrap();
+        crate::db::migrations::run_pending(&mut conn).unwrap();
+        conn
+    }
+
+    #[test]
+    fn sample_owners_are_valid() {
+        let conn = &mut connection();
+        for o in Owner::owners(conn).unwrap() {
+            let input = owner(&o.name, &o.phone, o.email.as_deref());
+            assert!(fields(input.validate()).is_empty(), "{}", o.name);
+        }
+    }
+
+    #[test]
+    fn pets_need_known_references() {
+        let conn = &mut connection();
+        let owner_id = Owner::owners(conn).unwrap()[0].id;
+
+        assert!(fields(pet("Rex", 0, 1, owner_id, None).validate(conn)).is_empty());
+        assert!(fields(pet("Rex", MAX_PET_AGE, 4, owner_id, Some(1)).validate(conn)).is_empty());
+        assert_eq!(
+            fields(pet("Rex", -1, 1, owner_id, None).validate(conn)),
+            ["age"]
+        );
+        assert_eq!(
+            fields(pet("Rex", MAX_PET_AGE + 1, 1, owner_id, None).validate(conn)),
+            ["age"]
+        );
+        assert_eq!(
+            fields(pet("", 3, 0, 0, Some(999)).validate(conn)),
+            ["name", "owner_id", "pet_type", "vet_id"]
+        );
+        assert_eq!(
+            fields(pet("Rex", 3, -1, owner_id, None).validate(conn)),
+            ["pet_type"]
+        );
+    }
+
+    #[test]
+    fn visits_need_a_known_vet() {
+        let conn = &mut connection();
+        let visit = |vet_id| VisitInput {
+            vet_id,
+            visit_date: NaiveDate::from_ymd_opt(2026, 10, 18).unwrap(),
+            notes: None,
+        };
+        assert!(fields(visit(1).validate(conn)).is_empty());
+        assert_eq!(fields(visit(0).validate(conn)), ["vet_id"]);
+        assert_eq!(fields(visit(999).validate(conn)), ["vet_id"]);
+    }
+}
@@ -3,78 +3,99 @@ use axum::{
     async_trait,
     extract::{Extension, FromRequestParts},
     http::{request::Parts, StatusCode},
-    middleware::from_extractor,
-    response::{Html, IntoResponse, Redirect, Response},
+    middleware::{self, from_extractor},
+    response::{IntoResponse, Redirect, Response},
     routing::{get, get_service, post},
     Router,
 };
 use axum_extra::extract::cookie::CookieJar;
 use context::Context;
 use db::models::user::User;
+use diesel::{Connection, SqliteConnection};
+use errors::{AppError, ErrorPages};
 use handlers::*;
-use redis::{Commands, RedisError};
+use logic::permissions::{require, Authorized, Can};
 use serde_json::Value;
-use settings::Settings;
-use tokio::net::TcpListener;
-use std::sync::Arc;
+use settings::{Args, Settings};
+use std::{sync::Arc, time::Duration};
 use tera::Tera;
 use tower_http::{services::ServeDir, trace::TraceLayer};
 use tracing::{debug, info};
 
+mod cli;
 mod context;
+mod csrf;
 mod db;
+mod errors;
 mod handlers;
+mod logging;
 mod logic;
+mod metrics;
+mod server;
+mod session;
 mod settings;
 
-#[derive(Debug)]
-pub struct AppError {
-    inner: anyhow::Error,
-}
-
-impl IntoResponse for AppError {
-    fn into_response(self) -> Response {
-        Html::from(format!("Oh, something bad happened: {}", &self.inner)).into_response()
-    }
-}
-
-impl<E> From<E> for AppError
-where
-    E: Into<anyhow::Error>,
-{
-    fn from(e: E) -> Self {
-        AppError { inner: e.into() }
-    }
-}
-
 #[tokio::main]
 async fn main() -> Result<()> {
     if std::env::var_os("RUST_LOG").is_none() {
         std::env::set_var("RUST_LOG", "warn,petclinic=debug")
     }
 
-    tracing_subscriber::fmt::init();
+    let args: Args = argh::from_env();
+    let settings = Arc::new(Settings::new(args.config_file)?);
+
+    logging::init(&settings)?;
 
-    let settings = Arc::new(Settings::new()?);
+    if let Some(command) = args.command {
+        let result = cli::run(command, &settings);
+        logging::shutdown();
+        return result;
+    }
 
     info!("Env: {settings:?}");
-    let state = Context::new(Arc::clone(&settings))?;
+    if settings.database.run_migrations {
+        let conn = &mut SqliteConnection::establish(&settings.database.path)?;
+        for name in db::migrations::run_pending(conn)? {
+            info!("Applied migration {name}");
+        }
+    }
+    let state = Arc::new(Context::new(Arc::clone(&settings))?);
+    let tera = get_tera_instance(settings.tera_templates.as_str());
 
     let app = get_public_routes()
         .merge(get_protected_routes())
-        .fallback(|| async { "fallback route?" })
-        .layer(TraceLayer::new_for_http())
-        .route_layer(Extension(Arc::new(state)))
+        // The API authenticates with bearer tokens, which browsers never send on their own
+        .route_layer(middleware::from_fn(csrf::protect))
+        .nest("/api/v1", api::routes())
+        .fallback(|| async { AppError::NotFound })
+        .layer(middleware::from_fn(errors::pages))
+        .layer(Extension(ErrorPages(Arc::new(tera.clone()))))
+        .layer(middleware::from_fn(metrics::track))
+        .layer(
+            TraceLayer::new_for_http()
+                .make_span_with(logging::make_span)
+                .on_response(logging::record_status),
+        )
+        .layer(middleware::from_fn(logging::request_id))
+        .route_layer(Extension(Arc::clone(&state)))
         .route_layer(Extension(settings.clone()))
-        .route_layer(Extension(get_tera_instance(
-            settings.tera_templates.as_str(),
-        )));
+        .route_layer(Extension(tera));
 
-    info!("Server started at: {:?}", settings.service_port);
+    let shutdown = async {
+        server::shutdown_signal().await;
+        state.start_draining();
+        // Keep accepting connections until load balancers saw /readyz fail
+        let delay = Duration::from_secs(settings.shutdown_delay_secs);
+        if !delay.is_zero() {
+            info!("Not ready anymore, closing listeners in {delay:?}");
+            tokio::time::sleep(delay).await;
+        }
+    };
+    server::serve(&settings, app, shutdown).await?;
 
-    let listener = TcpListener::bind(format!("127.0.0.1:{}", settings.service_port)).await?;
-    axum::serve(listener, app.into_make_service())
-        .await?;
+    state.close();
+    info!("Server stopped");
+    logging::shutdown();
 
     Ok(())
 }
@@ -82,8 +103,17 @@ async fn main() -> Result<()> {
 fn get_public_routes() -> Router {
     Router::new()
         .route("/", get(home::home))
-        .route("/logout", get(auth::logout))
+        .route("/logout", post(auth::logout))
         .route("/login", get(auth::login).post(auth::post_login))
+        .route(
+            "/login/two-factor",
+            get(auth::two_factor).post(auth::post_two_factor),
+        )
+        .route("/healthz", get(health::healthz))
+        .route("/readyz", get(health::readyz))
+        .route("/version", get(health::version))
+        .route("/metrics", get(health::metrics_text))
+        .route("/vets/:id/calendar.ics", get(calendar::feed))
         .nest_service(
             "/static",
             get_service(ServeDir::new("static")).handle_error(|_| async move {}),
@@ -91,16 +121,94 @@ fn get_public_routes() -> Router {
 }
 
 fn get_protected_routes() -> Router {
-    Router::new()
+    let view = Router::new()
+        .route("/appointments", get(appointments::calendar))
+        .route("/appointments/:id", get(appointments::get))
         .route("/vets", get(vets::list))
-        .route("/vets/save", post(vets::save))
         .route("/vets/:id", get(vets::get))
+        .route("/owners", get(owners::list))
+        .route("/owners/:id", get(owners::get))
         .route("/pets", get(pets::list))
-        .route("/pets/save", post(pets::save))
         .route("/pets/:id", get(pets::get))
-        .route("/vets/delete/:id", get(vets::delete))
-        .route("/pets/delete/:id", get(pets::delete))
-        .route_layer(from_extractor::<User>())
+        .route("/pets/:id/visits", get(visits::list))
+        .route("/pets/:id/visits/:visit_id", get(visits::get))
+        .route_layer(from_extractor::<Authorized<require::View>>());
+
+    let account = Router::new()
+        .route("/account/sessions", get(sessions::list))
+        .route("/account/sessions/revoke/:id", post(sessions::revoke))
+        .route("/account/two-factor", get(two_factor::settings))
+        .route("/account/two-factor/setup", post(two_factor::setup))
+        .route("/account/two-factor/enable", post(two_factor::enable))
+        .route(
+            "/account/two-factor/recovery-codes",
+            post(two_factor::recovery_codes),
+        )
+        .route("/account/two-factor/disable", post(two_factor::disable))
+        .route_layer(from_extractor::<Authorized<require::View>>());
+
+    let edit_pets = Router::new()
+        .route("/owners/save", post(owners::save))
+        .route("/pets/save", post(pets::save))
+        .route_layer(from_extractor::<Authorized<require::EditPets>>());
+
+    let delete_pets = Router::new()
+        .route(
+            "/owners/delete/:id",
+            get(owners::confirm_delete).post(owners::delete),
+        )
+        .route(
+            "/pets/delete/:id",
+            get(pets::confirm_delete).post(pets::delete),
+        )
+        .route_layer(from_extractor::<Authorized<require::DeletePets>>());
+
+    let edit_visits = Router::new()
+        .route("/pets/:id/visits/save", post(visits::save))
+        .route(
+            "/pets/:id/visits/delete/:visit_id",
+            get(visits::confirm_delete).post(visits::delete),
+        )
+        .route_layer(from_extractor::<Authorized<require::EditVisits>>());
+
+    let edit_appointments = Router::new()
+        .route("/appointments/save", post(appointments::save))
+        .route_layer(from_extractor::<Authorized<require::EditAppointments>>());
+
+    let manage_vets = Router::new()
+        .route("/vets/save", post(vets::save))
+        .route(
+            "/vets/delete/:id",
+            get(vets::confirm_delete).post(vets::delete),
+        )
+        .route("/vets/:id/calendar", post(calendar::new_token))
+        .route_layer(from_extractor::<Authorized<require::ManageVets>>());
+
+    let manage_users = Router::new()
+        .route("/users", get(users::list))
+        .route("/users/save", post(users::save))
+        .route("/users/:id", get(users::get))
+        .route("/users/disable/:id", post(users::disable))
+        .route("/users/enable/:id", post(users::enable))
+        .route("/users/unlock/:id", post(users::unlock))
+        .route("/users/logout/:id", post(users::logout))
+        .route("/users/reset-two-factor/:id", post(users::reset_two_factor))
+        .route_layer(from_extractor::<Authorized<require::ManageUsers>>());
+
+    let audit_log = Router::new()
+        .route("/audit", get(audit::list))
+        .route_layer(from_extractor::<Authorized<require::ViewAuditLog>>());
+
+    Router::new()
+        .merge(view)
+        .merge(account)
+        .merge(edit_pets)
+        .merge(delete_pets)
+        .merge(edit_visits)
+        .merge(edit_appointments)
+        .merge(manage_vets)
+        .merge(manage_users)
+        .merge(audit_log)
 }
 
 struct Principal {
@@ -134,65 +242,100 @@ fn get_tera_instance<'a>(tera_templates: impl Into<&'a str>) -> Tera {
     tera
 }
 
+/// The session user, loaded once per request. The permission layer
+/// ([`Authorized`]) extracts it first and leaves it in the request
+/// extensions, where handlers taking a `User` find it again.
 #[async_trait]
 impl<S> FromRequestParts<S> for User
 where
     S: Send + Sync,
 {
-    type Rejection = (StatusCode, Redirect);
+    type Rejection = Response;
 
     async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        if let Some(user) = parts.extensions.get::<User>() {
+            return Ok(user.clone());
+        }
+
+        // Browsers are sent to the login page, API clients just told
+        let json = errors::prefers_json(&parts.headers);
+        let login = move || {
+            if json {
+                AppError::Unauthorized.into_response()
+            } else {
+                (StatusCode::TEMPORARY_REDIRECT, Redirect::to("/login")).into_response()
+            }
+        };
+
         let context = Arc::clone(parts.extensions.get::<Arc<Context>>().ok_or_else(|| {
             tracing::debug!("Failed to get mut Context");
-            (StatusCode::TEMPORARY_REDIRECT, Redirect::to("/login"))
+            login()
         })?);
 
         let cookiejar = CookieJar::from_headers(&parts.headers);
 
         let tera = parts.extensions.get_mut::<Tera>().ok_or_else(|| {
             tracing::debug!("Failed to get mut Tera");
-            (StatusCode::TEMPORARY_REDIRECT, Redirect::to("/login"))
+            login()
         })?;
 
-        // check if the session cookie is valid against  redis
-        let mut connection = context.redis_connection.lock().await;
-        let cookie = cookiejar.get("axum_session").ok_or_else(|| {
+        let cookie = cookiejar.get(auth::SESSION_COOKIE).ok_or_else(|| {
             debug!("Session cookie not found, redirecting to login url");
-            (StatusCode::TEMPORARY_REDIRECT, Redirect::to("/login"))
+            login()
         })?;
 
-        let valid_session: Result<User, RedisError> = connection.get(cookie.value());
-
-        match valid_session {
-            Ok(user) => {
-                // refresh the key ttl
-                connection
-                    .expire(cookie.value(), context.settings.session.timeout)
-                    .map_err(|e| {
-                        tracing::debug!("{e:?}");
-                        (StatusCode::TEMPORARY_REDIRECT, Redirect::to("/login"))
-                    })?;
-
-                tera.register_function(
-                    "principal",
-                    Principal {
-                        user: Some(user.clone()),
-                    },
-                );
-
-                if context.settings.config_name == "development" {
-                    tera.full_reload().map_err(|e| {
-                        tracing::debug!("{e:?}");
-                        (StatusCode::TEMPORARY_REDIRECT, Redirect::to("/login"))
-                    })?;
-                }
-
-                Ok(user)
+        // check if the session cookie is valid against the session store
+        let session_user = match context.sessions.load(cookie.value()).await {
+            Ok(Some(user)) => user,
+            Ok(None) => return Err(login()),
+            Err(e) => {
+                tracing::error!("Cannot read session: {e:?}");
+                return Err(login());
             }
-            Err(_error) => {
-                // redis query failed
-                Err((StatusCode::TEMPORARY_REDIRECT, Redirect::to("/login")))
+        };
+
+        // The session keeps the user as of the login, role and disabled flag
+        // are read again as they may have changed since
+        let id = session_user.id;
+        let user = async {
+            let db_conn = context.db_connection().await?;
+            db_conn
+                .interact(move |conn| User::select_by_id(conn, id))
+                .await
+                .map_err(|e| anyhow::Error::msg(e.to_string()))?
+        }
+        .await;
+        let user = match user {
+            Ok(Some(user)) if !user.disabled => user,
+            Ok(_) => return Err(login()),
+            Err(e) => {
+                tracing::error!("Cannot read user {id}: {e:?}");
+                return Err(login());
             }
+        };
+
+        logging::record_user(&user.username);
+        tera.register_function(
+            "principal",
+            Principal {
+                user: Some(user.clone()),
+            },
+        );
+        tera.register_function(
+            "can",
+            Can {
+                role: Some(user.role),
+            },
+        );
+
+        if context.settings.config_name == "development" {
+            tera.full_reload().map_err(|e| {
+                tracing::debug!("{e:?}");
+                login()
+            })?;
         }
+
+        parts.extensions.insert(user.clone());
+        Ok(user)
     }
 }
@@ -0,0 +1,666 @@
+//! Listeners serving the app: plain HTTP or HTTPS on `bind_address`, a plain
+//! HTTP listener redirecting to HTTPS, or a Unix domain socket for running
+//! behind a reverse proxy.
+//!
+//! Every connection speaks HTTP/1.1 or HTTP/2, told apart by the HTTP/2
+//! connection preface. On shutdown the listeners close first, then every
+//! connection is shut down gracefully: idle ones close right away, the others
+//! once their running requests have finished.
+
+use crate::settings::{Settings, Tls};
+use anyhow::{Context as _, Result};
+use axum::{
+    body::{Body, Bytes},
+    extract::{ConnectInfo, Request},
+    http::{header, HeaderValue, StatusCode, Uri, Version},
+    response::{IntoResponse, Redirect, Response},
+    Router,
+};
+use hyper::{
+    body::Incoming,
+    server::conn::{http1, http2},
+    service::service_fn,
+};
+use hyper_util::rt::{TokioExecutor, TokioIo};
+use std::{
+    convert::Infallible,
+    fmt,
+    fs::{self, File},
+    future::Future,
+    io::{self, BufReader},
+    net::{IpAddr, SocketAddr},
+    path::Path,
+    pin::{pin, Pin},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, RwLock,
+    },
+    task::{Context, Poll},
+    time::{Duration, SystemTime},
+};
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, ReadBuf},
+    net::{TcpListener, UnixListener},
+    signal::unix::{signal, SignalKind},
+    sync::Notify,
+};
+use tokio_rustls::{
+    rustls::{
+        pki_types::{CertificateDer, PrivateKeyDer},
+        ServerConfig,
+    },
+    TlsAcceptor,
+};
+use tower::ServiceExt;
+use tracing::{debug, info, warn};
+
+/// Header a trusted proxy sets to the client address, see `trusted_proxies`.
+const REAL_IP_HEADER: &str = "x-real-ip";
+/// Time a client gets to complete the TLS handshake before it is dropped.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+/// First bytes sent by HTTP/2 clients, see RFC 9113 section 3.4.
+const HTTP2_PREFACE: &[u8] = b"PRI * HTTP/2.0\r\n\r\nSM\r\n\r\n";
+
+/// The address a request came from, available as `ConnectInfo<ClientAddr>`.
+/// `None` when a proxy forwarded it without being trusted to tell the client
+/// address, so it isn't mistaken for the proxy's own.
+#[derive(Clone, Copy, Debug)]
+pub struct ClientAddr(pub Option<IpAddr>);
+
+impl fmt::Display for ClientAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(ip) => ip.fmt(f),
+            None => f.write_str("an unknown address"),
+        }
+    }
+}
+
+/// Serves `app` until `shutdown` completes, then stops accepting connections
+/// and waits up to `drain_timeout_secs` for the running requests to finish.
+pub async fn serve(
+    settings: &Settings,
+    app: Router,
+    shutdown: impl Future<Output = ()>,
+) -> Result<()> {
+    let requests = Requests::default();
+
+    // Dropping the listeners when `shutdown` wins stops accepting connections,
+    // while those already accepted keep running on their own tasks
+    tokio::select! {
+        result = listen(settings, app, requests.clone()) => return result,
+        () = shutdown => {}
+    }
+    requests.start_draining();
+
+    let timeout = Duration::from_secs(settings.drain_timeout_secs);
+    info!(
+        "Shutting down, waiting up to {timeout:?} for {} running requests",
+        requests.running()
+    );
+    if tokio::time::timeout(timeout, requests.finished())
+        .await
+        .is_err()
+    {
+        warn!(
+            "Shutting down with {} requests still running",
+            requests.running()
+        );
+    }
+
+    if let Some(path) = &settings.unix_socket {
+        if let Err(e) = fs::remove_file(path) {
+            warn!("Cannot remove socket {path}: {e}");
+        }
+    }
+
+    Ok(())
+}
+
+/// Resolves on the first SIGTERM or SIGINT.
+pub async fn shutdown_signal() {
+    let terminate = async {
+        match signal(SignalKind::terminate()) {
+            Ok(mut terminate) => {
+                terminate.recv().await;
+            }
+            Err(e) => {
+                tracing::error!("Cannot listen for SIGTERM: {e}");
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+
+    tokio::select! {
+        _ = tokio::signal::ctrl_c() => info!("Received SIGINT"),
+        () = terminate => info!("Received SIGTERM"),
+    }
+}
+
+async fn listen(settings: &Settings, app: Router, requests: Requests) -> Result<()> {
+    if let Some(path) = &settings.unix_socket {
+        let trust_real_ip = settings.trusts_proxy(None);
+        return serve_unix(Path::new(path), trust_real_ip, app, requests).await;
+    }
+
+    let ip: IpAddr = settings.bind_address.parse()?;
+    let addr = SocketAddr::new(ip, settings.service_port as u16);
+    match &settings.tls {
+        Some(tls) => {
+            let redirect = async {
+                let Some(port) = tls.redirect_port else {
+                    return Ok(());
+                };
+                let redirect_addr = SocketAddr::new(ip, port as u16);
+                let listener = TcpListener::bind(redirect_addr).await?;
+                info!("Redirecting http://{redirect_addr} to HTTPS");
+                let https_port = settings.service_port as u16;
+                serve_tcp(
+                    listener,
+                    settings,
+                    redirect_router(https_port),
+                    requests.clone(),
+                )
+                .await
+            };
+            tokio::try_join!(
+                redirect,
+                serve_tls(addr, settings, tls, app, requests.clone())
+            )?;
+            Ok(())
+        }
+        None => {
+            let listener = TcpListener::bind(addr).await?;
+            info!("Listening on http://{addr}");
+            serve_tcp(listener, settings, app, requests).await
+        }
+    }
+}
+
+async fn serve_tcp(
+    listener: TcpListener,
+    settings: &Settings,
+    app: Router,
+    requests: Requests,
+) -> Result<()> {
+    loop {
+        let (stream, remote_addr) = listener.accept().await?;
+        let trust_real_ip = settings.trusts_proxy(Some(remote_addr.ip()));
+        serve_connection(
+            stream,
+            Some(remote_addr),
+            trust_real_ip,
+            app.clone(),
+            requests.clone(),
+        );
+    }
+}
+
+async fn serve_tls(
+    addr: SocketAddr,
+    settings: &Settings,
+    tls: &Tls,
+    app: Router,
+    requests: Requests,
+) -> Result<()> {
+    let certificates = Certificates::load(tls)?;
+    let listener = TcpListener::bind(addr).await?;
+    info!("Listening on https://{addr}");
+
+    let accept = async {
+        loop {
+            let (stream, remote_addr) = listener.accept().await?;
+            let trust_real_ip = settings.trusts_proxy(Some(remote_addr.ip()));
+            let acceptor = certificates.acceptor();
+            let app = app.clone();
+            let requests = requests.clone();
+            // The handshake runs on the connection's task so a slow client
+            // doesn't hold up the accept loop, nor keep its task forever
+            tokio::spawn(async move {
+                match tokio::time::timeout(HANDSHAKE_TIMEOUT, acceptor.accept(stream)).await {
+                    Ok(Ok(stream)) => {
+                        serve_connection(stream, Some(remote_addr), trust_real_ip, app, requests)
+                    }
+                    Ok(Err(e)) => debug!("TLS handshake with {remote_addr} failed: {e}"),
+                    Err(_) => debug!("TLS handshake with {remote_addr} timed out"),
+                }
+            });
+        }
+    };
+
+    tokio::select! {
+        () = certificates.watch(Duration::from_secs(tls.reload_interval_secs)) => Ok(()),
+        result = accept => result,
+    }
+}
+
+async fn serve_unix(
+    path: &Path,
+    trust_real_ip: bool,
+    app: Router,
+    requests: Requests,
+) -> Result<()> {
+    // A socket file left behind by a previous run would make the bind fail
+    if path.exists() {
+        fs::remove_file(path)
+            .with_context(|| format!("Cannot remove stale socket {}", path.display()))?;
+    }
+    let listener =
+        UnixListener::bind(path).with_context(|| format!("Cannot listen on {}", path.display()))?;
+    info!("Listening on unix:{}", path.display());
+
+    loop {
+        let (stream, _) = listener.accept().await?;
+        serve_connection(stream, None, trust_real_ip, app.clone(), requests.clone());
+    }
+}
+
+/// Serves the requests of one connection on its own task. `remote_addr` is
+/// `None` for Unix socket connections. With `trust_real_ip` the client
+/// address is taken from the proxy's `X-Real-IP` header instead.
+fn serve_connection<I>(
+    io: I,
+    remote_addr: Option<SocketAddr>,
+    trust_real_ip: bool,
+    app: Router,
+    requests: Requests,
+) where
+    I: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    let connection_requests = requests.clone();
+    let service = service_fn(move |request: Request<Incoming>| {
+        let mut request = request.map(Body::new);
+        let client_addr = if trust_real_ip {
+            real_ip(&request)
+        } else {
+            remote_addr.map(|addr| addr.ip())
+        };
+        request
+            .extensions_mut()
+            .insert(ConnectInfo(ClientAddr(client_addr)));
+        let keep_alive = request.version() <= Version::HTTP_11;
+
+        let running = requests.start();
+        let response = app.clone().oneshot(request);
+        async move {
+            let mut response = response.await?;
+            // Lets HTTP/1 clients know not to reuse a connection that is about
+            // to be closed
+            if keep_alive && running.draining() {
+                response
+                    .headers_mut()
+                    .insert(header::CONNECTION, HeaderValue::from_static("close"));
+            }
+            Ok::<_, Infallible>(response)
+        }
+    });
+
+    tokio::spawn(async move {
+        let requests = connection_requests;
+        let (http2, io) = match read_version(io).await {
+            Ok(version) => version,
+            Err(e) => return debug!("Connection closed before the first request: {e}"),
+        };
+        let io = TokioIo::new(io);
+
+        // Each connection type has its own `graceful_shutdown`, called once
+        // shutdown starts: HTTP/1 closes after the running request, HTTP/2
+        // sends GOAWAY and lets the open streams finish
+        let result = if http2 {
+            let mut connection =
+                pin!(http2::Builder::new(TokioExecutor::new()).serve_connection(io, service));
+            tokio::select! {
+                result = connection.as_mut() => result,
+                () = requests.draining_started() => {
+                    connection.as_mut().graceful_shutdown();
+                    connection.await
+                }
+            }
+        } else {
+            // upgrades needed for websockets
+            let mut connection = pin!(http1::Builder::new()
+                .serve_connection(io, service)
+                .with_upgrades());
+            tokio::select! {
+                result = connection.as_mut() => result,
+                () = requests.draining_started() => {
+                    connection.as_mut().graceful_shutdown();
+                    connection.await
+                }
+            }
+        };
+        if let Err(e) = result {
+            debug!("Connection closed with an error: {e}");
+        }
+    });
+}
+
+/// Reads as much of `io` as it takes to tell whether the client speaks
+/// HTTP/2, handing back the stream with those bytes put back in front.
+async fn read_version<I>(mut io: I) -> io::Result<(bool, Rewind<I>)>
+where
+    I: AsyncRead + Unpin,
+{
+    let mut read = Vec::with_capacity(HTTP2_PREFACE.len());
+    while read.len() < HTTP2_PREFACE.len() && HTTP2_PREFACE.starts_with(&read) {
+        let mut buf = [0; HTTP2_PREFACE.len()];
+        let n = io
+            .read(&mut buf[..HTTP2_PREFACE.len() - read.len()])
+            .await?;
+        if n == 0 {
+            break;
+        }
+        read.extend_from_slice(&buf[..n]);
+    }
+
+    let http2 = read == HTTP2_PREFACE;
+    Ok((
+        http2,
+        Rewind {
+            prefix: Bytes::from(read),
+            io,
+        },
+    ))
+}
+
+/// A stream replaying `prefix` before reading on from `io`.
+struct Rewind<I> {
+    prefix: Bytes,
+    io: I,
+}
+
+impl<I: AsyncRead + Unpin> AsyncRead for Rewind<I> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        if self.prefix.is_empty() {
+            return Pin::new(&mut self.io).poll_read(cx, buf);
+        }
+        let n = self.prefix.len().min(buf.remaining());
+        buf.put_slice(&self.prefix.split_to(n));
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<I: AsyncWrite + Unpin> AsyncWrite for Rewind<I> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.io).poll_write(cx, buf)
+    }
+
+    fn poll_write_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[io::IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.io).poll_write_vectored(cx, bufs)
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.io.is_write_vectored()
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.io).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.io).poll_shutdown(cx)
+    }
+}
+
+/// Counts the requests being handled, so shutdown can wait for them.
+#[derive(Clone, Default)]
+struct Requests(Arc<RequestsState>);
+
+#[derive(Default)]
+struct RequestsState {
+    running: AtomicUsize,
+    finished: Notify,
+    draining: AtomicBool,
+    draining_started: Notify,
+}
+
+impl Requests {
+    fn start(&self) -> RunningRequest {
+        self.0.running.fetch_add(1, Ordering::SeqCst);
+        RunningRequest(Arc::clone(&self.0))
+    }
+
+    fn running(&self) -> usize {
+        self.0.running.load(Ordering::SeqCst)
+    }
+
+    fn start_draining(&self) {
+        self.0.draining.store(true, Ordering::SeqCst);
+        self.0.draining_started.notify_waiters();
+    }
+
+    /// Resolves once shutdown has started.
+    async fn draining_started(&self) {
+        let started = self.0.draining_started.notified();
+        if self.0.draining.load(Ordering::SeqCst) {
+            return;
+        }
+        started.await;
+    }
+
+    /// Resolves once no request is running.
+    async fn finished(&self) {
+        loop {
+            let finished = self.0.finished.notified();
+            if self.running() == 0 {
+                return;
+            }
+            finished.await;
+        }
+    }
+}
+
+/// Marks its request finished when dropped, also when the client went away.
+struct RunningRequest(Arc<RequestsState>);
+
+impl RunningRequest {
+    fn draining(&self) -> bool {
+        self.0.draining.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for RunningRequest {
+    fn drop(&mut self) {
+        if self.0.running.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.0.finished.notify_waiters();
+        }
+    }
+}
+
+fn real_ip(request: &Request) -> Option<IpAddr> {
+    request
+        .headers()
+        .get(REAL_IP_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse().ok())
+}
+
+fn redirect_router(https_port: u16) -> Router {
+    Router::new()
+        .fallback(move |request: Request| async move { redirect_to_https(request, https_port) })
+}
+
+fn redirect_to_https(request: Request, https_port: u16) -> Response {
+    let Some(host) = request
+        .headers()
+        .get(header::HOST)
+        .and_then(|host| host.to_str().ok())
+    else {
+        return (StatusCode::BAD_REQUEST, "Missing Host header").into_response();
+    };
+    // Drop the port of the plain listener, keeping IPv6 brackets intact
+    let host = match host.rsplit_once(':') {
+        Some((name, port)) if !port.contains(']') => name,
+        _ => host,
+    };
+    let authority = if https_port == 443 {
+        host.to_string()
+    } else {
+        format!("{host}:{https_port}")
+    };
+    let path = request
+        .uri()
+        .path_and_query()
+        .map(|path| path.as_str())
+        .unwrap_or("/");
+
+    match Uri::builder()
+        .scheme("https")
+        .authority(authority)
+        .path_and_query(path)
+        .build()
+    {
+        Ok(uri) => Redirect::permanent(&uri.to_string()).into_response(),
+        Err(_) => (StatusCode::BAD_REQUEST, "Invalid Host header").into_response(),
+    }
+}
+
+/// The TLS config in use, replaced when the certificate files change so a
+/// renewed certificate is picked up without a restart.
+struct Certificates {
+    cert_path: String,
+    key_path: String,
+    config: RwLock<Arc<ServerConfig>>,
+    modified: RwLock<Option<SystemTime>>,
+}
+
+impl Certificates {
+    fn load(tls: &Tls) -> Result<Self> {
+        let modified = last_modified(&tls.cert_path, &tls.key_path);
+        let config = server_config(&tls.cert_path, &tls.key_path)?;
+
+        Ok(Certificates {
+            cert_path: tls.cert_path.clone(),
+            key_path: tls.key_path.clone(),
+            config: RwLock::new(Arc::new(config)),
+            modified: RwLock::new(modified),
+        })
+    }
+
+    fn acceptor(&self) -> TlsAcceptor {
+        let config = self.config.read().unwrap_or_else(|e| e.into_inner());
+        TlsAcceptor::from(Arc::clone(&config))
+    }
+
+    /// Checks the files every `interval` and reloads them once they changed.
+    /// A broken certificate is logged and the previous one kept.
+    async fn watch(&self, interval: Duration) {
+        let mut ticks = tokio::time::interval(interval);
+        ticks.tick().await;
+        loop {
+            ticks.tick().await;
+
+            let modified = last_modified(&self.cert_path, &self.key_path);
+            if modified == *self.modified.read().unwrap_or_else(|e| e.into_inner()) {
+                continue;
+            }
+            match server_config(&self.cert_path, &self.key_path) {
+                Ok(config) => {
+                    *self.config.write().unwrap_or_else(|e| e.into_inner()) = Arc::new(config);
+                    *self.modified.write().unwrap_or_else(|e| e.into_inner()) = modified;
+                    info!("Reloaded TLS certificate {}", self.cert_path);
+                }
+                Err(e) => warn!("Keeping the current TLS certificate: {e:?}"),
+            }
+        }
+    }
+}
+
+/// Latest modification of either file, `None` while one cannot be read.
+fn last_modified(cert_path: &str, key_path: &str) -> Option<SystemTime> {
+    let modified = |path: &str| fs::metadata(path).and_then(|m| m.modified()).ok();
+    Some(modified(cert_path)?.max(modified(key_path)?))
+}
+
+fn server_config(cert_path: &str, key_path: &str) -> Result<ServerConfig> {
+    let certs = rustls_pemfile::certs(&mut BufReader::new(
+        File::open(cert_path).with_context(|| format!("Cannot open {cert_path}"))?,
+    ))
+    .collect::<Result<Vec<CertificateDer>, _>>()
+    .with_context(|| format!("Cannot parse {cert_path}"))?;
+    if certs.is_empty() {
+        anyhow::bail!("No certificate found in {cert_path}");
+    }
+
+    let key: PrivateKeyDer = rustls_pemfile::private_key(&mut BufReader::new(
+        File::open(key_path).with_context(|| format!("Cannot open {key_path}"))?,
+    ))
+    .with_context(|| format!("Cannot parse {key_path}"))?
+    .with_context(|| format!("No private key found in {key_path}"))?;
+
+    let mut config = ServerConfig::builder()
+        .with_no_client_auth()
+        .with_single_cert(certs, key)?;
+    config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::routing::get;
+    use tokio::{io::AsyncWriteExt, net::TcpStream};
+
+    async fn read_all(mut io: impl AsyncRead + Unpin) -> Vec<u8> {
+        let mut read = Vec::new();
+        io.read_to_end(&mut read).await.unwrap();
+        read
+    }
+
+    #[tokio::test]
+    async fn versions_are_told_apart_by_the_preface() {
+        let request = b"GET / HTTP/1.1\r\nHost: localhost\r\n\r\n";
+        let (http2, io) = read_version(&request[..]).await.unwrap();
+        assert!(!http2);
+        assert_eq!(read_all(io).await, request);
+
+        let request = [HTTP2_PREFACE, b"\0\0\0\x04\0\0\0\0\0"].concat();
+        let (http2, io) = read_version(&request[..]).await.unwrap();
+        assert!(http2);
+        assert_eq!(read_all(io).await, request);
+
+        let (http2, io) = read_version(&b"PRI"[..]).await.unwrap();
+        assert!(!http2);
+        assert_eq!(read_all(io).await, b"PRI");
+    }
+
+    #[tokio::test]
+    async fn idle_connections_close_on_shutdown() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let requests = Requests::default();
+        let app = Router::new().route("/", get(|| async { "ok" }));
+        let server = requests.clone();
+        tokio::spawn(async move { serve_tcp(listener, &Settings::default(), app, server).await });
+
+        let mut client = TcpStream::connect(addr).await.unwrap();
+        client
+            .write_all(b"GET / HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .await
+            .unwrap();
+        let mut response = [0; 1024];
+        let n = client.read(&mut response).await.unwrap();
+        assert!(response[..n].starts_with(b"HTTP/1.1 200 OK"));
+
+        // The kept alive connection is closed by the server
+        requests.start_draining();
+        let n = tokio::time::timeout(Duration::from_secs(5), client.read(&mut response))
+            .await
+            .expect("connection still open")
+            .unwrap();
+        assert_eq!(n, 0);
+    }
+}
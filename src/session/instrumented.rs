@@ -0,0 +1,121 @@
+use super::{FailedLogins, SessionBackend, SessionInfo, SessionStore};
+use crate::{db::models::user::User, metrics::metrics};
+use anyhow::Result;
+use async_trait::async_trait;
+use std::{future::Future, time::Duration};
+use tracing::Instrument;
+
+/// Wraps a store to trace and time its commands and count their errors.
+pub struct InstrumentedStore<S> {
+    inner: S,
+    backend: &'static str,
+}
+
+impl<S: SessionStore> InstrumentedStore<S> {
+    pub fn new(inner: S, backend: SessionBackend) -> Self {
+        Self {
+            inner,
+            backend: backend.as_str(),
+        }
+    }
+
+    async fn observe<T>(
+        &self,
+        command: &'static str,
+        result: impl Future<Output = Result<T>>,
+    ) -> Result<T> {
+        let labels = [self.backend, command];
+        let timer = metrics()
+            .session_command_duration
+            .with_label_values(&labels)
+            .start_timer();
+        let span = tracing::info_span!(
+            "session",
+            otel.name = %format_args!("session.{command}"),
+            backend = self.backend,
+            command,
+        );
+        let result = result.instrument(span).await;
+        timer.observe_duration();
+
+        if result.is_err() {
+            metrics()
+                .session_command_errors
+                .with_label_values(&labels)
+                .inc();
+        }
+        result
+    }
+}
+
+#[async_trait]
+impl<S: SessionStore> SessionStore for InstrumentedStore<S> {
+    async fn save(&self, key: &str, user: &User, info: &SessionInfo) -> Result<()> {
+        self.observe("save", self.inner.save(key, user, info)).await
+    }
+
+    async fn load(&self, key: &str) -> Result<Option<User>> {
+        self.observe("load", self.inner.load(key)).await
+    }
+
+    async fn remove(&self, key: &str) -> Result<()> {
+        self.observe("remove", self.inner.remove(key)).await
+    }
+
+    async fn user_sessions(&self, user_id: i32) -> Result<Vec<(String, SessionInfo)>> {
+        self.observe("user_sessions", self.inner.user_sessions(user_id))
+            .await
+    }
+
+    async fn remove_user_sessions(&self, user_id: i32) -> Result<()> {
+        self.observe(
+            "remove_user_sessions",
+            self.inner.remove_user_sessions(user_id),
+        )
+        .await
+    }
+
+    async fn save_pending_login(&self, key: &str, user_id: i32, ttl: Duration) -> Result<()> {
+        self.observe(
+            "save_pending_login",
+            self.inner.save_pending_login(key, user_id, ttl),
+        )
+        .await
+    }
+
+    async fn pending_login(&self, key: &str) -> Result<Option<i32>> {
+        self.observe("pending_login", self.inner.pending_login(key))
+            .await
+    }
+
+    async fn remove_pending_login(&self, key: &str) -> Result<()> {
+        self.observe("remove_pending_login", self.inner.remove_pending_login(key))
+            .await
+    }
+
+    async fn record_failed_login(&self, key: &str, window: Duration) -> Result<FailedLogins> {
+        self.observe(
+            "record_failed_login",
+            self.inner.record_failed_login(key, window),
+        )
+        .await
+    }
+
+    async fn failed_logins(&self, key: &str) -> Result<Option<FailedLogins>> {
+        self.observe("failed_logins", self.inner.failed_logins(key))
+            .await
+    }
+
+    async fn clear_failed_logins(&self, key: &str) -> Result<()> {
+        self.observe("clear_failed_logins", self.inner.clear_failed_logins(key))
+            .await
+    }
+
+    async fn ping(&self) -> Result<()> {
+        self.observe("ping", self.inner.ping()).await
+    }
+
+    fn close(&self) {
+        self.inner.close();
+    }
+}
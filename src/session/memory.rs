@@ -0,0 +1,138 @@
+use super::{FailedLogins, SessionInfo, SessionStore};
+use crate::db::models::user::User;
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::Utc;
+use std::{
+    collections::HashMap,
+    sync::{Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+/// Process-local sessions, lost on restart and not shared between instances.
+/// Meant for development and tests.
+pub struct MemoryStore {
+    sessions: Mutex<HashMap<String, (User, SessionInfo, Instant)>>,
+    pending_logins: Mutex<HashMap<String, (i32, Instant)>>,
+    failed_logins: Mutex<HashMap<String, (FailedLogins, Instant)>>,
+    ttl: Duration,
+}
+
+impl MemoryStore {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            sessions: Mutex::new(HashMap::new()),
+            pending_logins: Mutex::new(HashMap::new()),
+            failed_logins: Mutex::new(HashMap::new()),
+            ttl,
+        }
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> Result<MutexGuard<'_, T>> {
+    mutex
+        .lock()
+        .map_err(|_| anyhow::anyhow!("session map lock poisoned"))
+}
+
+#[async_trait]
+impl SessionStore for MemoryStore {
+    async fn save(&self, key: &str, user: &User, info: &SessionInfo) -> Result<()> {
+        let now = Instant::now();
+        let mut sessions = lock(&self.sessions)?;
+        sessions.retain(|_, (_, _, expires_at)| *expires_at > now);
+        sessions.insert(
+            key.to_string(),
+            (user.clone(), info.clone(), now + self.ttl),
+        );
+        Ok(())
+    }
+
+    async fn load(&self, key: &str) -> Result<Option<User>> {
+        let now = Instant::now();
+        let mut sessions = lock(&self.sessions)?;
+        match sessions.get_mut(key) {
+            Some((user, info, expires_at)) if *expires_at > now => {
+                *expires_at = now + self.ttl;
+                info.last_seen_at = Utc::now();
+                Ok(Some(user.clone()))
+            }
+            Some(_) => {
+                sessions.remove(key);
+                Ok(None)
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn remove(&self, key: &str) -> Result<()> {
+        lock(&self.sessions)?.remove(key);
+        Ok(())
+    }
+
+    async fn user_sessions(&self, user_id: i32) -> Result<Vec<(String, SessionInfo)>> {
+        let now = Instant::now();
+        Ok(lock(&self.sessions)?
+            .iter()
+            .filter(|(_, (_, info, expires_at))| info.user_id == user_id && *expires_at > now)
+            .map(|(key, (_, info, _))| (key.clone(), info.clone()))
+            .collect())
+    }
+
+    async fn remove_user_sessions(&self, user_id: i32) -> Result<()> {
+        lock(&self.sessions)?.retain(|_, (_, info, _)| info.user_id != user_id);
+        Ok(())
+    }
+
+    async fn save_pending_login(&self, key: &str, user_id: i32, ttl: Duration) -> Result<()> {
+        let now = Instant::now();
+        let mut pending_logins = lock(&self.pending_logins)?;
+        pending_logins.retain(|_, (_, expires_at)| *expires_at > now);
+        pending_logins.insert(key.to_string(), (user_id, now + ttl));
+        Ok(())
+    }
+
+    async fn pending_login(&self, key: &str) -> Result<Option<i32>> {
+        let now = Instant::now();
+        Ok(lock(&self.pending_logins)?
+            .get(key)
+            .filter(|(_, expires_at)| *expires_at > now)
+            .map(|(user_id, _)| *user_id))
+    }
+
+    async fn remove_pending_login(&self, key: &str) -> Result<()> {
+        lock(&self.pending_logins)?.remove(key);
+        Ok(())
+    }
+
+    async fn record_failed_login(&self, key: &str, window: Duration) -> Result<FailedLogins> {
+        let now = Instant::now();
+        let mut failed_logins = lock(&self.failed_logins)?;
+        failed_logins.retain(|_, (_, expires_at)| *expires_at > now);
+
+        let count = failed_logins.get(key).map_or(0, |(failed, _)| failed.count) + 1;
+        let failed = FailedLogins {
+            count,
+            last_failed_at: Utc::now(),
+        };
+        failed_logins.insert(key.to_string(), (failed, now + window));
+        Ok(failed)
+    }
+
+    async fn failed_logins(&self, key: &str) -> Result<Option<FailedLogins>> {
+        let now = Instant::now();
+        Ok(lock(&self.failed_logins)?
+            .get(key)
+            .filter(|(_, expires_at)| *expires_at > now)
+            .map(|(failed, _)| *failed))
+    }
+
+    async fn clear_failed_logins(&self, key: &str) -> Result<()> {
+        lock(&self.failed_logins)?.remove(key);
+        Ok(())
+    }
+
+    async fn ping(&self) -> Result<()> {
+        lock(&self.sessions).map(|_| ())
+    }
+}
@@ -0,0 +1,255 @@
+//! Server-side storage of login sessions, keyed by the `axum_session` cookie.
+//! Sessions are indexed per user so they can be listed and revoked.
+//!
+//! The store also keeps logins still waiting for their second factor and the
+//! failed login counters used against password guessing.
+//!
+//! The backend is chosen with `session.backend` so development and tests can
+//! run without a Redis server.
+
+use crate::{db::models::user::User, settings::Settings};
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use deadpool_diesel::sqlite::Pool;
+use serde::{Deserialize, Serialize};
+use std::{sync::Arc, time::Duration};
+
+mod instrumented;
+mod memory;
+mod redis;
+mod sqlite;
+
+pub use self::{
+    instrumented::InstrumentedStore, memory::MemoryStore, redis::RedisStore, sqlite::SqliteStore,
+};
+
+/// Where sessions are kept, set by `backend` in the `[session]` settings.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionBackend {
+    #[default]
+    Redis,
+    Sqlite,
+    Memory,
+}
+
+impl SessionBackend {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SessionBackend::Redis => "redis",
+            SessionBackend::Sqlite => "sqlite",
+            SessionBackend::Memory => "memory",
+        }
+    }
+}
+
+/// Where and when a session was started and last used.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct SessionInfo {
+    pub user_id: i32,
+    pub created_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+    pub ip: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+/// Failed logins counted under one key, such as a username or client address.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FailedLogins {
+    pub count: u32,
+    pub last_failed_at: DateTime<Utc>,
+}
+
+/// Sessions expire after `ttl` of inactivity: every successful [`load`]
+/// extends them again.
+///
+/// [`load`]: SessionStore::load
+#[async_trait]
+pub trait SessionStore: Send + Sync {
+    /// Stores `user` under `key`, replacing any previous session.
+    async fn save(&self, key: &str, user: &User, info: &SessionInfo) -> Result<()>;
+
+    /// The user logged in with `key`, if the session exists and hasn't expired.
+    /// Also records it as last seen now.
+    async fn load(&self, key: &str) -> Result<Option<User>>;
+
+    async fn remove(&self, key: &str) -> Result<()>;
+
+    /// The live sessions of `user_id` with their keys.
+    async fn user_sessions(&self, user_id: i32) -> Result<Vec<(String, SessionInfo)>>;
+
+    /// Ends every session of `user_id`.
+    async fn remove_user_sessions(&self, user_id: i32) -> Result<()>;
+
+    /// Remembers for `ttl` that `user_id` entered the right password but still
+    /// has to enter a two-factor code.
+    async fn save_pending_login(&self, key: &str, user_id: i32, ttl: Duration) -> Result<()>;
+
+    async fn pending_login(&self, key: &str) -> Result<Option<i32>>;
+
+    async fn remove_pending_login(&self, key: &str) -> Result<()>;
+
+    /// Counts one more failed login under `key`. The counter is forgotten
+    /// once `window` passes without another failure.
+    async fn record_failed_login(&self, key: &str, window: Duration) -> Result<FailedLogins>;
+
+    async fn failed_logins(&self, key: &str) -> Result<Option<FailedLogins>>;
+
+    async fn clear_failed_logins(&self, key: &str) -> Result<()>;
+
+    /// Checks that the backend is reachable, for the readiness probe.
+    async fn ping(&self) -> Result<()>;
+
+    /// Releases the backend's connections when the server shuts down.
+    fn close(&self) {}
+}
+
+/// Builds the store selected in `settings`, recording metrics of its commands.
+pub fn from_settings(settings: &Settings, pool: &Pool) -> Result<Arc<dyn SessionStore>> {
+    let ttl = Duration::from_secs(settings.session.timeout as u64);
+    let backend = settings.session.backend;
+
+    Ok(match backend {
+        SessionBackend::Redis => Arc::new(InstrumentedStore::new(
+            RedisStore::new(&settings.redis, ttl)?,
+            backend,
+        )),
+        SessionBackend::Sqlite => Arc::new(InstrumentedStore::new(
+            SqliteStore::new(pool.clone(), ttl),
+            backend,
+        )),
+        SessionBackend::Memory => Arc::new(InstrumentedStore::new(MemoryStore::new(ttl), backend)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::{migrations, models::user::Role, PooledConnection};
+    use deadpool_diesel::sqlite::{Manager, Runtime};
+
+    const TTL: Duration = Duration::from_secs(60);
+
+    fn user(id: i32) -> User {
+        User {
+            id,
+            username: format!("user{id}"),
+            password: String::new(),
+            disabled: false,
+            role: Role::Vet,
+        }
+    }
+
+    fn info(user_id: i32) -> SessionInfo {
+        let started = Utc::now() - chrono::Duration::hours(1);
+        SessionInfo {
+            user_id,
+            created_at: started,
+            last_seen_at: started,
+            ip: Some("192.0.2.1".to_string()),
+            user_agent: Some("test".to_string()),
+        }
+    }
+
+    /// A store on its own in-memory database, kept alive by the single pooled connection.
+    async fn sqlite_store(ttl: Duration) -> SqliteStore {
+        let pool = Pool::builder(Manager::new(":memory:", Runtime::Tokio1))
+            .max_size(1)
+            .build()
+            .unwrap();
+        PooledConnection::from(pool.get().await.unwrap())
+            .interact(migrations::run_pending)
+            .await
+            .unwrap()
+            .unwrap();
+        SqliteStore::new(pool, ttl)
+    }
+
+    async fn keys(store: &dyn SessionStore, user_id: i32) -> Vec<String> {
+        let mut keys: Vec<_> = store
+            .user_sessions(user_id)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect();
+        keys.sort();
+        keys
+    }
+
+    /// What every backend has to do the same way.
+    async fn contract(store: &dyn SessionStore) {
+        for (key, id) in [("a", 1), ("b", 1), ("c", 2)] {
+            store.save(key, &user(id), &info(id)).await.unwrap();
+        }
+        assert_eq!(store.load("a").await.unwrap(), Some(user(1)));
+        assert_eq!(store.load("unknown").await.unwrap(), None);
+
+        // Loading a session marks it as seen
+        let sessions = store.user_sessions(1).await.unwrap();
+        let (_, seen) = sessions.iter().find(|(key, _)| key == "a").unwrap();
+        assert!(seen.last_seen_at > info(1).last_seen_at);
+        assert_eq!(seen.ip.as_deref(), Some("192.0.2.1"));
+
+        assert_eq!(keys(store, 1).await, ["a", "b"]);
+        assert_eq!(keys(store, 2).await, ["c"]);
+        store.remove("a").await.unwrap();
+        assert_eq!(store.load("a").await.unwrap(), None);
+        assert_eq!(keys(store, 1).await, ["b"]);
+
+        store.remove_user_sessions(1).await.unwrap();
+        assert_eq!(store.load("b").await.unwrap(), None);
+        assert!(keys(store, 1).await.is_empty());
+        assert_eq!(store.load("c").await.unwrap(), Some(user(2)));
+
+        store.save_pending_login("p", 1, TTL).await.unwrap();
+        assert_eq!(store.pending_login("p").await.unwrap(), Some(1));
+        assert_eq!(store.pending_login("q").await.unwrap(), None);
+        store.remove_pending_login("p").await.unwrap();
+        assert_eq!(store.pending_login("p").await.unwrap(), None);
+
+        assert_eq!(store.record_failed_login("f", TTL).await.unwrap().count, 1);
+        let failed = store.record_failed_login("f", TTL).await.unwrap();
+        assert_eq!(failed.count, 2);
+        assert_eq!(store.failed_logins("f").await.unwrap(), Some(failed));
+        assert_eq!(store.failed_logins("g").await.unwrap(), None);
+        store.clear_failed_logins("f").await.unwrap();
+        assert_eq!(store.failed_logins("f").await.unwrap(), None);
+        assert_eq!(store.record_failed_login("f", TTL).await.unwrap().count, 1);
+
+        store.ping().await.unwrap();
+    }
+
+    /// `store` has a zero TTL, so nothing it keeps outlives the call saving it.
+    async fn expiry(store: &dyn SessionStore) {
+        store.save("a", &user(1), &info(1)).await.unwrap();
+        store
+            .save_pending_login("p", 1, Duration::ZERO)
+            .await
+            .unwrap();
+        store
+            .record_failed_login("f", Duration::ZERO)
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        assert_eq!(store.load("a").await.unwrap(), None);
+        assert!(keys(store, 1).await.is_empty());
+        assert_eq!(store.pending_login("p").await.unwrap(), None);
+        assert_eq!(store.failed_logins("f").await.unwrap(), None);
+        assert_eq!(store.record_failed_login("f", TTL).await.unwrap().count, 1);
+    }
+
+    #[tokio::test]
+    async fn memory_store_keeps_the_contract() {
+        contract(&MemoryStore::new(TTL)).await;
+        expiry(&MemoryStore::new(Duration::ZERO)).await;
+    }
+
+    #[tokio::test]
+    async fn sqlite_store_keeps_the_contract() {
+        contract(&sqlite_store(TTL).await).await;
+        expiry(&sqlite_store(Duration::ZERO).await).await;
+    }
+}
@@ -0,0 +1,278 @@
+use super::{FailedLogins, SessionInfo, SessionStore};
+use crate::{db::models::user::User, settings};
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{TimeZone, Utc};
+use deadpool_redis::{Config, Pool, Runtime};
+use redis::{AsyncCommands, RedisResult};
+use std::{collections::HashMap, future::Future, time::Duration};
+
+/// Keeps each session as a JSON string under `session:<key>`, letting Redis
+/// expire it. Its [`SessionInfo`] is a hash under `session_info:<key>` and
+/// the keys of every user's sessions are in the set `user_sessions:<id>`.
+///
+/// Connections come from a pool that pings them before reuse, so connections
+/// broken by a Redis restart are replaced instead of failing every request.
+pub struct RedisStore {
+    pool: Pool,
+    command_timeout: Duration,
+    ttl: Duration,
+}
+
+impl RedisStore {
+    pub fn new(settings: &settings::Redis, ttl: Duration) -> Result<Self> {
+        let redis_url = match &settings.password {
+            Some(password) => format!("redis://:{}@{}", password, settings.server),
+            None => format!("redis://{}", settings.server),
+        };
+
+        let connect_timeout = Duration::from_millis(settings.connect_timeout_ms);
+        let pool = Config::from_url(redis_url)
+            .builder()?
+            .max_size(settings.pool_size)
+            .wait_timeout(Some(Duration::from_millis(settings.wait_timeout_ms)))
+            .create_timeout(Some(connect_timeout))
+            .recycle_timeout(Some(connect_timeout))
+            .runtime(Runtime::Tokio1)
+            .build()?;
+
+        Ok(Self {
+            pool,
+            command_timeout: Duration::from_millis(settings.command_timeout_ms),
+            ttl,
+        })
+    }
+
+    /// Awaits a single command, giving up after the configured command timeout.
+    async fn timed<T>(&self, command: impl Future<Output = RedisResult<T>>) -> Result<T> {
+        match tokio::time::timeout(self.command_timeout, command).await {
+            Ok(result) => Ok(result?),
+            Err(_) => anyhow::bail!("Redis command timed out after {:?}", self.command_timeout),
+        }
+    }
+}
+
+#[async_trait]
+impl SessionStore for RedisStore {
+    async fn save(&self, key: &str, user: &User, info: &SessionInfo) -> Result<()> {
+        let mut conn = self.pool.get().await?;
+        let value = serde_json::to_string(user)?;
+        let ttl = self.ttl.as_secs() as usize;
+        let fields = [
+            ("user_id", info.user_id.to_string()),
+            ("created_at", info.created_at.timestamp().to_string()),
+            ("last_seen_at", info.last_seen_at.timestamp().to_string()),
+            ("ip", info.ip.clone().unwrap_or_default()),
+            ("user_agent", info.user_agent.clone().unwrap_or_default()),
+        ];
+
+        self.timed(
+            redis::pipe()
+                .set_ex(session_key(key), value, ttl)
+                .ignore()
+                .hset_multiple(info_key(key), &fields)
+                .ignore()
+                .expire(info_key(key), ttl)
+                .ignore()
+                .sadd(user_sessions_key(user.id), key)
+                .ignore()
+                .expire(user_sessions_key(user.id), ttl)
+                .ignore()
+                .query_async::<_, ()>(&mut conn),
+        )
+        .await
+    }
+
+    async fn load(&self, key: &str) -> Result<Option<User>> {
+        if !is_valid_key(key) {
+            return Ok(None);
+        }
+        let mut conn = self.pool.get().await?;
+        let user: Option<User> = self.timed(conn.get(session_key(key))).await?;
+        if let Some(user) = &user {
+            let ttl = self.ttl.as_secs() as usize;
+            self.timed(
+                redis::pipe()
+                    .expire(session_key(key), ttl)
+                    .ignore()
+                    .hset(info_key(key), "last_seen_at", Utc::now().timestamp())
+                    .ignore()
+                    .expire(info_key(key), ttl)
+                    .ignore()
+                    .expire(user_sessions_key(user.id), ttl)
+                    .ignore()
+                    .query_async::<_, ()>(&mut conn),
+            )
+            .await?;
+        }
+        Ok(user)
+    }
+
+    async fn remove(&self, key: &str) -> Result<()> {
+        if !is_valid_key(key) {
+            return Ok(());
+        }
+        let mut conn = self.pool.get().await?;
+        let user_id: Option<i32> = self.timed(conn.hget(info_key(key), "user_id")).await?;
+
+        let mut pipe = redis::pipe();
+        pipe.del(&[session_key(key), info_key(key)]).ignore();
+        if let Some(user_id) = user_id {
+            pipe.srem(user_sessions_key(user_id), key).ignore();
+        }
+        self.timed(pipe.query_async::<_, ()>(&mut conn)).await
+    }
+
+    async fn user_sessions(&self, user_id: i32) -> Result<Vec<(String, SessionInfo)>> {
+        let mut conn = self.pool.get().await?;
+        let keys: Vec<String> = self
+            .timed(conn.smembers(user_sessions_key(user_id)))
+            .await?;
+
+        let mut sessions = Vec::with_capacity(keys.len());
+        for key in keys {
+            let fields: HashMap<String, String> = self.timed(conn.hgetall(info_key(&key))).await?;
+            match parse_info(&fields) {
+                Some(info) => sessions.push((key, info)),
+                // Expired since it was indexed
+                None => {
+                    self.timed(conn.srem::<_, _, ()>(user_sessions_key(user_id), &key))
+                        .await?
+                }
+            }
+        }
+        Ok(sessions)
+    }
+
+    async fn remove_user_sessions(&self, user_id: i32) -> Result<()> {
+        let mut conn = self.pool.get().await?;
+        let keys: Vec<String> = self
+            .timed(conn.smembers(user_sessions_key(user_id)))
+            .await?;
+
+        let mut doomed = vec![user_sessions_key(user_id)];
+        for key in keys {
+            doomed.push(info_key(&key));
+            doomed.push(session_key(&key));
+        }
+        self.timed(conn.del::<_, ()>(doomed)).await
+    }
+
+    async fn save_pending_login(&self, key: &str, user_id: i32, ttl: Duration) -> Result<()> {
+        let mut conn = self.pool.get().await?;
+        self.timed(conn.set_ex::<_, _, ()>(pending_key(key), user_id, ttl.as_secs() as usize))
+            .await
+    }
+
+    async fn pending_login(&self, key: &str) -> Result<Option<i32>> {
+        let mut conn = self.pool.get().await?;
+        self.timed(conn.get(pending_key(key))).await
+    }
+
+    async fn remove_pending_login(&self, key: &str) -> Result<()> {
+        let mut conn = self.pool.get().await?;
+        self.timed(conn.del::<_, ()>(pending_key(key))).await
+    }
+
+    async fn record_failed_login(&self, key: &str, window: Duration) -> Result<FailedLogins> {
+        let mut conn = self.pool.get().await?;
+        let last_failed_at = Utc::now();
+        let window = window.as_secs() as usize;
+        // INCR keeps concurrent failures from being lost
+        let (count,): (u32,) = self
+            .timed(
+                redis::pipe()
+                    .incr(count_key(key), 1)
+                    .expire(count_key(key), window)
+                    .ignore()
+                    .set_ex(time_key(key), last_failed_at.timestamp(), window)
+                    .ignore()
+                    .query_async(&mut conn),
+            )
+            .await?;
+
+        Ok(FailedLogins {
+            count,
+            last_failed_at,
+        })
+    }
+
+    async fn failed_logins(&self, key: &str) -> Result<Option<FailedLogins>> {
+        let mut conn = self.pool.get().await?;
+        let (count, timestamp): (Option<u32>, Option<i64>) = self
+            .timed(conn.get(&[count_key(key), time_key(key)]))
+            .await?;
+
+        Ok(count.zip(timestamp).and_then(|(count, timestamp)| {
+            Some(FailedLogins {
+                count,
+                last_failed_at: Utc.timestamp_opt(timestamp, 0).single()?,
+            })
+        }))
+    }
+
+    async fn clear_failed_logins(&self, key: &str) -> Result<()> {
+        let mut conn = self.pool.get().await?;
+        self.timed(conn.del::<_, ()>(&[count_key(key), time_key(key)]))
+            .await
+    }
+
+    async fn ping(&self) -> Result<()> {
+        let mut conn = self.pool.get().await?;
+        let pong: String = self
+            .timed(redis::cmd("PING").query_async(&mut conn))
+            .await?;
+        anyhow::ensure!(pong == "PONG", "unexpected PING reply: {pong}");
+        Ok(())
+    }
+
+    fn close(&self) {
+        self.pool.close();
+    }
+}
+
+/// Session keys are alphanumeric cookie values, anything else is refused
+/// rather than put into a Redis key.
+fn is_valid_key(key: &str) -> bool {
+    !key.is_empty() && key.bytes().all(|b| b.is_ascii_alphanumeric())
+}
+
+fn session_key(key: &str) -> String {
+    format!("session:{key}")
+}
+
+fn info_key(key: &str) -> String {
+    format!("session_info:{key}")
+}
+
+fn user_sessions_key(user_id: i32) -> String {
+    format!("user_sessions:{user_id}")
+}
+
+fn parse_info(fields: &HashMap<String, String>) -> Option<SessionInfo> {
+    let timestamp = |name: &str| {
+        let seconds = fields.get(name)?.parse().ok()?;
+        Utc.timestamp_opt(seconds, 0).single()
+    };
+    let text = |name: &str| fields.get(name).filter(|value| !value.is_empty()).cloned();
+
+    Some(SessionInfo {
+        user_id: fields.get("user_id")?.parse().ok()?,
+        created_at: timestamp("created_at")?,
+        last_seen_at: timestamp("last_seen_at")?,
+        ip: text("ip"),
+        user_agent: text("user_agent"),
+    })
+}
+
+fn pending_key(key: &str) -> String {
+    format!("pending_login:{key}")
+}
+
+fn count_key(key: &str) -> String {
+    format!("{key}:count")
+}
+
+fn time_key(key: &str) -> String {
+    format!("{key}:last_failed_at")
+}
@@ -0,0 +1,210 @@
+use super::{FailedLogins, SessionInfo, SessionStore};
+use crate::db::{
+    models::{
+        login_failure::LoginFailure, pending_login::PendingLogin, session::Session, user::User,
+    },
+    PooledConnection,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::Utc;
+use deadpool_diesel::sqlite::Pool;
+use diesel::{sql_query, RunQueryDsl, SqliteConnection};
+use std::time::Duration;
+
+/// Sessions kept in the `session` table of the application database.
+pub struct SqliteStore {
+    pool: Pool,
+    ttl: chrono::Duration,
+}
+
+impl SqliteStore {
+    pub fn new(pool: Pool, ttl: Duration) -> Self {
+        Self {
+            pool,
+            ttl: chrono::Duration::seconds(ttl.as_secs() as i64),
+        }
+    }
+
+    async fn interact<F, R>(&self, f: F) -> Result<R>
+    where
+        F: FnOnce(&mut SqliteConnection) -> Result<R> + Send + 'static,
+        R: Send + 'static,
+    {
+        PooledConnection::from(self.pool.get().await?)
+            .interact(f)
+            .await
+            .map_err(|e| anyhow::Error::msg(e.to_string()))?
+    }
+}
+
+#[async_trait]
+impl SessionStore for SqliteStore {
+    async fn save(&self, key: &str, user: &User, info: &SessionInfo) -> Result<()> {
+        let now = Utc::now().naive_utc();
+        let session = Session {
+            key: key.to_string(),
+            user_id: user.id,
+            data: serde_json::to_string(user)?,
+            created_at: info.created_at.naive_utc(),
+            expires_at: now + self.ttl,
+            last_seen_at: info.last_seen_at.naive_utc(),
+            ip: info.ip.clone(),
+            user_agent: info.user_agent.clone(),
+        };
+
+        self.interact(move |conn| {
+            Session::delete_expired(conn, now)?;
+            session.save(conn)?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn load(&self, key: &str) -> Result<Option<User>> {
+        let key = key.to_string();
+        let ttl = self.ttl;
+
+        self.interact(move |conn| {
+            let now = Utc::now().naive_utc();
+            let Some(session) = Session::select_live(conn, &key, now)? else {
+                return Ok(None);
+            };
+            Session::touch(conn, &key, now, now + ttl)?;
+            Ok(Some(serde_json::from_str(&session.data)?))
+        })
+        .await
+    }
+
+    async fn remove(&self, key: &str) -> Result<()> {
+        let key = key.to_string();
+        self.interact(move |conn| {
+            Session::delete_by_key(conn, &key)?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn user_sessions(&self, user_id: i32) -> Result<Vec<(String, SessionInfo)>> {
+        self.interact(move |conn| {
+            let sessions = Session::select_live_by_user_id(conn, user_id, Utc::now().naive_utc())?;
+            Ok(sessions
+                .into_iter()
+                .map(|session| {
+                    let info = SessionInfo {
+                        user_id: session.user_id,
+                        created_at: session.created_at.and_utc(),
+                        last_seen_at: session.last_seen_at.and_utc(),
+                        ip: session.ip,
+                        user_agent: session.user_agent,
+                    };
+                    (session.key, info)
+                })
+                .collect())
+        })
+        .await
+    }
+
+    async fn remove_user_sessions(&self, user_id: i32) -> Result<()> {
+        self.interact(move |conn| {
+            Session::delete_by_user_id(conn, user_id)?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn save_pending_login(&self, key: &str, user_id: i32, ttl: Duration) -> Result<()> {
+        let now = Utc::now().naive_utc();
+        let pending = PendingLogin {
+            key: key.to_string(),
+            user_id,
+            expires_at: now + chrono::Duration::seconds(ttl.as_secs() as i64),
+        };
+
+        self.interact(move |conn| {
+            PendingLogin::delete_expired(conn, now)?;
+            pending.save(conn)?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn pending_login(&self, key: &str) -> Result<Option<i32>> {
+        let key = key.to_string();
+        self.interact(move |conn| {
+            Ok(
+                PendingLogin::select_live(conn, &key, Utc::now().naive_utc())?
+                    .map(|pending| pending.user_id),
+            )
+        })
+        .await
+    }
+
+    async fn remove_pending_login(&self, key: &str) -> Result<()> {
+        let key = key.to_string();
+        self.interact(move |conn| {
+            PendingLogin::delete_by_key(conn, &key)?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn record_failed_login(&self, key: &str, window: Duration) -> Result<FailedLogins> {
+        let key = key.to_string();
+        let window = chrono::Duration::seconds(window.as_secs() as i64);
+
+        self.interact(move |conn| {
+            conn.immediate_transaction(|conn| {
+                let now = Utc::now();
+                let count = LoginFailure::select_live(conn, &key, now.naive_utc())?
+                    .map_or(0, |failure| failure.count)
+                    + 1;
+                LoginFailure {
+                    key,
+                    count,
+                    last_failed_at: now.naive_utc(),
+                    expires_at: (now + window).naive_utc(),
+                }
+                .save(conn)?;
+
+                Ok(FailedLogins {
+                    count: count as u32,
+                    last_failed_at: now,
+                })
+            })
+        })
+        .await
+    }
+
+    async fn failed_logins(&self, key: &str) -> Result<Option<FailedLogins>> {
+        let key = key.to_string();
+        self.interact(move |conn| {
+            Ok(
+                LoginFailure::select_live(conn, &key, Utc::now().naive_utc())?.map(|failure| {
+                    FailedLogins {
+                        count: failure.count as u32,
+                        last_failed_at: failure.last_failed_at.and_utc(),
+                    }
+                }),
+            )
+        })
+        .await
+    }
+
+    async fn clear_failed_logins(&self, key: &str) -> Result<()> {
+        let key = key.to_string();
+        self.interact(move |conn| {
+            LoginFailure::delete_by_key(conn, &key)?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn ping(&self) -> Result<()> {
+        self.interact(|conn| {
+            sql_query("SELECT 1 FROM session LIMIT 1").execute(conn)?;
+            Ok(())
+        })
+        .await
+    }
+}
@@ -1,75 +1,530 @@
+use crate::cli::Command;
+use crate::session::SessionBackend;
 use argh::FromArgs;
 use config::FileFormat;
-use config::{Config, ConfigError, File};
+use config::{Config, ConfigError, Environment, File};
 use serde::Deserialize;
 use std::env;
-use std::path::Path;
+use std::fmt;
+use std::net::{IpAddr, SocketAddr};
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Deserialize, Default)]
 #[allow(unused)]
 pub struct Database {
     pub path: String,
     pub connections: usize,
+    /// Apply pending migrations when the server starts.
+    #[serde(default)]
+    pub run_migrations: bool,
 }
 
-#[derive(Debug, Deserialize, Default)]
+#[derive(Deserialize, Default)]
 #[allow(unused)]
 pub struct Redis {
     pub server: String,
     pub password: Option<String>,
+    #[serde(default = "Redis::default_pool_size")]
+    pub pool_size: usize,
+    /// Milliseconds to wait for a free pooled connection.
+    #[serde(default = "Redis::default_timeout_ms")]
+    pub wait_timeout_ms: u64,
+    /// Milliseconds to wait while (re)connecting to the server.
+    #[serde(default = "Redis::default_timeout_ms")]
+    pub connect_timeout_ms: u64,
+    /// Milliseconds to wait for the reply to a single command.
+    #[serde(default = "Redis::default_timeout_ms")]
+    pub command_timeout_ms: u64,
+}
+
+// Hand written so the password never ends up in the logs
+impl fmt::Debug for Redis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Redis")
+            .field("server", &self.server)
+            .field("password", &self.password.as_ref().map(|_| "[redacted]"))
+            .field("pool_size", &self.pool_size)
+            .field("wait_timeout_ms", &self.wait_timeout_ms)
+            .field("connect_timeout_ms", &self.connect_timeout_ms)
+            .field("command_timeout_ms", &self.command_timeout_ms)
+            .finish()
+    }
+}
+
+impl Redis {
+    fn default_pool_size() -> usize {
+        16
+    }
+
+    fn default_timeout_ms() -> u64 {
+        1000
+    }
 }
 
 #[derive(Debug, Deserialize, Default)]
 #[allow(unused)]
 pub struct Session {
     pub timeout: usize,
+    #[serde(default)]
+    pub backend: SessionBackend,
+    /// Only send the session cookies over HTTPS.
+    #[serde(default)]
+    pub secure_cookie: bool,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[allow(unused)]
+pub struct Tls {
+    /// PEM file with the certificate chain, leaf first.
+    pub cert_path: String,
+    /// PEM file with the private key.
+    pub key_path: String,
+    /// Port of a plain HTTP listener redirecting every request to HTTPS.
+    pub redirect_port: Option<u32>,
+    /// Seconds between checks of the files for a renewed certificate.
+    #[serde(default = "Tls::default_reload_interval_secs")]
+    pub reload_interval_secs: u64,
+}
+
+impl Tls {
+    fn default_reload_interval_secs() -> u64 {
+        60
+    }
+}
+
+#[derive(Deserialize, Default)]
+#[allow(unused)]
+pub struct Metrics {
+    /// Bearer token Prometheus has to send to scrape `/metrics`, which
+    /// answers 403 when unset.
+    pub token: Option<String>,
+}
+
+impl fmt::Debug for Metrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Metrics")
+            .field("token", &self.token.as_ref().map(|_| "[redacted]"))
+            .finish()
+    }
+}
+
+/// How log lines are written.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LogFormat {
+    /// Human readable lines.
+    #[default]
+    Pretty,
+    /// One JSON object per line, for log shippers.
+    Json,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[allow(unused)]
+pub struct Log {
+    #[serde(default)]
+    pub format: LogFormat,
+}
+
+/// Export of traces to an OpenTelemetry collector, over OTLP/gRPC.
+#[derive(Debug, Deserialize)]
+#[allow(unused)]
+pub struct Otel {
+    /// Collector to send spans to.
+    #[serde(default = "Otel::default_endpoint")]
+    pub endpoint: String,
+    /// `service.name` resource attribute of the exported spans.
+    #[serde(default = "Otel::default_service_name")]
+    pub service_name: String,
+    /// Share of requests to trace, between 0 and 1.
+    #[serde(default = "Otel::default_sample_ratio")]
+    pub sample_ratio: f64,
+}
+
+impl Otel {
+    fn default_endpoint() -> String {
+        String::from("http://127.0.0.1:4317")
+    }
+
+    fn default_service_name() -> String {
+        String::from("petclinic")
+    }
+
+    fn default_sample_ratio() -> f64 {
+        1.0
+    }
 }
 
 #[derive(Debug, Deserialize, Default)]
 #[allow(unused)]
 pub struct Settings {
     pub config_name: String,
+    #[serde(default = "Settings::default_bind_address")]
+    pub bind_address: String,
     pub service_port: u32,
+    /// Where users reach the server, such as `https://clinic.example.com`,
+    /// for absolute links like calendar feed URLs. See [`Settings::base_url`].
+    pub public_url: Option<String>,
+    /// Serve HTTPS on `service_port` instead of plain HTTP.
+    pub tls: Option<Tls>,
+    /// Listen on this Unix domain socket instead of `bind_address:service_port`.
+    pub unix_socket: Option<String>,
+    /// Proxies whose `X-Real-IP` header is taken as the client address: IP
+    /// addresses, or `"unix"` for the connections on `unix_socket`.
+    #[serde(default)]
+    pub trusted_proxies: Vec<String>,
+    /// Seconds between failing the readiness probe and closing the listeners
+    /// on shutdown, for load balancers to stop sending new requests.
+    #[serde(default = "Settings::default_shutdown_delay_secs")]
+    pub shutdown_delay_secs: u64,
+    /// Seconds to let running requests finish after SIGTERM or SIGINT.
+    #[serde(default = "Settings::default_drain_timeout_secs")]
+    pub drain_timeout_secs: u64,
     pub database: Database,
+    /// Only needed with the Redis session backend.
+    #[serde(default)]
     pub redis: Redis,
     pub tera_templates: String,
     pub session: Session,
+    #[serde(default)]
+    pub metrics: Metrics,
+    #[serde(default)]
+    pub log: Log,
+    /// Export traces when set.
+    pub otel: Option<Otel>,
 }
 
 /// Available Arguments
-#[derive(Debug, Deserialize, Default, FromArgs)]
+#[derive(Debug, Default, FromArgs)]
 #[allow(unused)]
-struct Args {
-    /// config file setup
+pub struct Args {
+    /// path of the config file to use instead of `petclinic_config/<RUN_MODE>.toml`
     #[argh(option)]
     pub config_file: Option<String>,
+
+    #[argh(subcommand)]
+    pub command: Option<Command>,
 }
 
+/// Prefix of the environment variables overriding config keys, with `__`
+/// separating the key path: `PETCLINIC__DATABASE__PATH` sets `database.path`.
+const ENV_PREFIX: &str = "PETCLINIC";
+const ENV_SEPARATOR: &str = "__";
+/// Optional untracked file next to the config file, for machine specific
+/// overrides and secrets.
+const LOCAL_CONFIG: &str = "local.toml";
+/// Entry of `trusted_proxies` standing for the proxy in front of `unix_socket`.
+const UNIX_PROXY: &str = "unix";
+
 impl Settings {
-    pub fn new() -> Result<Self, ConfigError> {
-        let args: Args = argh::from_env();
-
-        let run_mode = if let Some(config_file) = args.config_file {
-            let root = Path::new("/etc/petclinic");
-            env::set_current_dir(root).unwrap();
-            config_file
-        } else {
-            env::var("RUN_MODE").unwrap_or(String::from("development"))
+    fn default_bind_address() -> String {
+        String::from("127.0.0.1")
+    }
+
+    fn default_shutdown_delay_secs() -> u64 {
+        5
+    }
+
+    fn default_drain_timeout_secs() -> u64 {
+        30
+    }
+
+    /// `public_url` without a trailing slash, falling back to the address the
+    /// server listens on.
+    pub fn base_url(&self) -> String {
+        if let Some(url) = &self.public_url {
+            return url.trim_end_matches('/').to_string();
+        }
+        let scheme = if self.tls.is_some() { "https" } else { "http" };
+        let ip = self
+            .bind_address
+            .parse()
+            .unwrap_or_else(|_| Self::default_bind_address().parse().unwrap());
+        format!(
+            "{scheme}://{}",
+            SocketAddr::new(ip, self.service_port as u16)
+        )
+    }
+
+    /// Whether the `X-Real-IP` header sent by `peer` is believed, `None`
+    /// being a connection on the Unix socket.
+    pub fn trusts_proxy(&self, peer: Option<IpAddr>) -> bool {
+        self.trusted_proxies.iter().any(|proxy| match peer {
+            Some(ip) => proxy.parse::<IpAddr>().is_ok_and(|proxy| proxy == ip),
+            None => proxy == UNIX_PROXY,
+        })
+    }
+
+    /// Layers, later ones overriding earlier ones: the config file, the local
+    /// override file next to it and `PETCLINIC__*` environment variables.
+    pub fn new(config_file: Option<String>) -> Result<Self, ConfigError> {
+        let config_file = match config_file {
+            Some(config_file) => PathBuf::from(config_file),
+            None => {
+                let run_mode = env::var("RUN_MODE").unwrap_or(String::from("development"));
+                Path::new("petclinic_config").join(format!("{run_mode}.toml"))
+            }
         };
+        if !config_file.is_file() {
+            return Err(ConfigError::Message(format!(
+                "Config file {} not found",
+                config_file.display()
+            )));
+        }
+        let local_file = config_file.with_file_name(LOCAL_CONFIG);
 
         let s = Config::builder()
+            .add_source(File::from(config_file.as_path()).format(FileFormat::Toml))
             .add_source(
-                File::new(&format!("petclinic_config/{}", run_mode), FileFormat::Toml)
+                File::from(local_file.as_path())
+                    .format(FileFormat::Toml)
                     .required(false),
             )
-            .add_source(File::new(&run_mode.to_string(), FileFormat::Toml).required(false))
-            // Local configuration file
-            // This file shouldn't be checked in to git
-            // .add_source(File::with_name("src/config/local").required(false))
-            // .add_source(Environment::with_prefix("petclinic-"))
+            .add_source(
+                Environment::with_prefix(ENV_PREFIX)
+                    .prefix_separator(ENV_SEPARATOR)
+                    .separator(ENV_SEPARATOR)
+                    .try_parsing(true),
+            )
             .build()?;
 
-        // You can deserialize (and thus freeze) the entire configuration as
-        s.try_deserialize()
+        // Errors name the offending key, e.g. `database.connections`
+        let settings: Settings = serde_path_to_error::deserialize(s).map_err(|e| {
+            ConfigError::Message(format!("Invalid config key `{}`: {}", e.path(), e.inner()))
+        })?;
+        settings.validate()?;
+
+        Ok(settings)
+    }
+
+    fn validate(&self) -> Result<(), ConfigError> {
+        let invalid = |key: &str, reason: &str| {
+            Err(ConfigError::Message(format!(
+                "Invalid config key `{key}`: {reason}"
+            )))
+        };
+
+        if self.bind_address.parse::<IpAddr>().is_err() {
+            return invalid("bind_address", "must be an IPv4 or IPv6 address");
+        }
+        if !(1..=u16::MAX as u32).contains(&self.service_port) {
+            return invalid("service_port", "must be between 1 and 65535");
+        }
+        if let Some(tls) = &self.tls {
+            if self.unix_socket.is_some() {
+                return invalid(
+                    "unix_socket",
+                    "cannot be combined with [tls], terminate TLS in the proxy instead",
+                );
+            }
+            if tls.cert_path.trim().is_empty() {
+                return invalid("tls.cert_path", "must not be empty");
+            }
+            if tls.key_path.trim().is_empty() {
+                return invalid("tls.key_path", "must not be empty");
+            }
+            if let Some(port) = tls.redirect_port {
+                if !(1..=u16::MAX as u32).contains(&port) || port == self.service_port {
+                    return invalid(
+                        "tls.redirect_port",
+                        "must be between 1 and 65535 and differ from service_port",
+                    );
+                }
+            }
+            if tls.reload_interval_secs == 0 {
+                return invalid("tls.reload_interval_secs", "must be at least 1");
+            }
+        }
+        if let Some(path) = &self.unix_socket {
+            if path.trim().is_empty() {
+                return invalid("unix_socket", "must not be empty");
+            }
+        }
+        if let Some(url) = &self.public_url {
+            if !url.starts_with("http://") && !url.starts_with("https://") {
+                return invalid("public_url", "must be an http:// or https:// URL");
+            }
+        }
+        if self
+            .trusted_proxies
+            .iter()
+            .any(|proxy| proxy != UNIX_PROXY && proxy.parse::<IpAddr>().is_err())
+        {
+            return invalid(
+                "trusted_proxies",
+                "must only list IP addresses and \"unix\"",
+            );
+        }
+        if let Some(otel) = &self.otel {
+            if !otel.endpoint.starts_with("http://") && !otel.endpoint.starts_with("https://") {
+                return invalid("otel.endpoint", "must be an http:// or https:// URL");
+            }
+            if otel.service_name.trim().is_empty() {
+                return invalid("otel.service_name", "must not be empty");
+            }
+            if !(0.0..=1.0).contains(&otel.sample_ratio) {
+                return invalid("otel.sample_ratio", "must be between 0 and 1");
+            }
+        }
+        if self.database.path.trim().is_empty() {
+            return invalid("database.path", "must not be empty");
+        }
+        if self.database.connections == 0 {
+            return invalid("database.connections", "must be at least 1");
+        }
+        if self.tera_templates.trim().is_empty() {
+            return invalid("tera_templates", "must not be empty");
+        }
+        if self
+            .metrics
+            .token
+            .as_ref()
+            .is_some_and(|token| token.trim().is_empty())
+        {
+            return invalid("metrics.token", "must not be empty");
+        }
+        if self.session.timeout == 0 {
+            return invalid("session.timeout", "must be at least 1 second");
+        }
+        if self.session.backend == SessionBackend::Redis {
+            if self.redis.server.trim().is_empty() {
+                return invalid("redis.server", "is required by the redis session backend");
+            }
+            if self.redis.pool_size == 0 {
+                return invalid("redis.pool_size", "must be at least 1");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs, process, sync::Mutex};
+
+    /// [`Settings::new`] reads the process environment, tests using it take turns.
+    static ENV: Mutex<()> = Mutex::new(());
+
+    const BASE: &str = r#"
+config_name = "test"
+service_port = 3000
+tera_templates = "templates/**/*"
+
+[database]
+path = "base.sqlite"
+connections = 4
+
+[session]
+timeout = 60
+backend = "memory"
+"#;
+
+    /// A config file in a directory of its own, removed when dropped.
+    struct ConfigDir(PathBuf);
+
+    impl ConfigDir {
+        fn new(name: &str, local: Option<&str>) -> Self {
+            let dir = env::temp_dir().join(format!("petclinic-{}-{name}", process::id()));
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join("test.toml"), BASE).unwrap();
+            if let Some(local) = local {
+                fs::write(dir.join(LOCAL_CONFIG), local).unwrap();
+            }
+            Self(dir)
+        }
+
+        fn load(&self) -> Result<Settings, ConfigError> {
+            Settings::new(Some(self.0.join("test.toml").display().to_string()))
+        }
+    }
+
+    impl Drop for ConfigDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    /// Sets an environment variable until dropped.
+    struct EnvVar(&'static str);
+
+    impl EnvVar {
+        fn set(name: &'static str, value: &str) -> Self {
+            env::set_var(name, value);
+            Self(name)
+        }
+    }
+
+    impl Drop for EnvVar {
+        fn drop(&mut self) {
+            env::remove_var(self.0);
+        }
+    }
+
+    #[test]
+    fn later_sources_override_earlier_ones() {
+        let _env = ENV.lock().unwrap_or_else(|e| e.into_inner());
+
+        let base = ConfigDir::new("base", None);
+        let settings = base.load().unwrap();
+        assert_eq!(settings.database.path, "base.sqlite");
+        assert_eq!(settings.database.connections, 4);
+        assert_eq!(settings.bind_address, "127.0.0.1");
+
+        let local = ConfigDir::new("local", Some("[database]\npath = \"local.sqlite\"\n"));
+        let settings = local.load().unwrap();
+        assert_eq!(settings.database.path, "local.sqlite");
+        assert_eq!(settings.database.connections, 4);
+
+        let _path = EnvVar::set("PETCLINIC__DATABASE__PATH", "env.sqlite");
+        let _connections = EnvVar::set("PETCLINIC__DATABASE__CONNECTIONS", "6");
+        let settings = local.load().unwrap();
+        assert_eq!(settings.database.path, "env.sqlite");
+        assert_eq!(settings.database.connections, 6);
+    }
+
+    #[test]
+    fn errors_name_the_bad_key() {
+        let _env = ENV.lock().unwrap_or_else(|e| e.into_inner());
+
+        let dir = ConfigDir::new("bad-type", Some("[session]\ntimeout = \"soon\"\n"));
+        let message = dir.load().unwrap_err().to_string();
+        assert!(
+            message.starts_with("Invalid config key `session.timeout`: "),
+            "{message}"
+        );
+
+        let dir = ConfigDir::new("bad-value", None);
+        let _connections = EnvVar::set("PETCLINIC__DATABASE__CONNECTIONS", "0");
+        assert_eq!(
+            dir.load().unwrap_err().to_string(),
+            "Invalid config key `database.connections`: must be at least 1"
+        );
+    }
+
+    #[test]
+    fn missing_config_files_are_reported() {
+        let dir = ConfigDir::new("missing", None);
+        let path = dir.0.join("missing.toml").display().to_string();
+        assert_eq!(
+            Settings::new(Some(path.clone())).unwrap_err().to_string(),
+            format!("Config file {path} not found")
+        );
+    }
+
+    #[test]
+    fn base_url_prefers_public_url() {
+        let mut settings = Settings {
+            bind_address: "0.0.0.0".to_string(),
+            service_port: 3000,
+            ..Settings::default()
+        };
+        assert_eq!(settings.base_url(), "http://0.0.0.0:3000");
+        settings.bind_address = "::1".to_string();
+        assert_eq!(settings.base_url(), "http://[::1]:3000");
+
+        settings.public_url = Some("https://clinic.example.com/".to_string());
+        assert_eq!(settings.base_url(), "https://clinic.example.com");
     }
 }